@@ -0,0 +1,191 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use toml::Table;
+
+// `yearYYYY_dayDD` → (year, day)
+fn parse_day_name(name: &str) -> Option<(u16, u8)> {
+    let (year, day) = name.strip_prefix("year")?.split_once("_day")?;
+
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+// days of years whose feature is off aren't compiled, so they get no tests and no inputs
+fn year_enabled(year: u16) -> bool {
+    env::var_os(format!("CARGO_FEATURE_YEAR{}", year)).is_some()
+}
+
+fn sorted_entries(dir: &Path) -> Vec<PathBuf> {
+    let mut entries = fs::read_dir(dir)
+        .map(|entries| entries.flatten().map(|entry| entry.path()).collect())
+        .unwrap_or_else(|_| vec![]);
+    entries.sort();
+
+    entries
+}
+
+fn has_extension(path: &Path, extension: &str) -> bool {
+    path.extension().is_some_and(|ext| ext == extension)
+}
+
+// one test per example and expected part for tests/examples.rs, see src/examples
+fn example_tests(manifest_dir: &Path) -> String {
+    let mut tests = String::new();
+
+    println!("cargo:rerun-if-changed=examples");
+
+    for dir in sorted_entries(&manifest_dir.join("examples")) {
+        let Some(day_name) = dir.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        let Some((year, day)) = parse_day_name(day_name).filter(|&(year, _)| year_enabled(year))
+        else {
+            continue;
+        };
+
+        for path in sorted_entries(&dir) {
+            if !has_extension(&path, "toml") {
+                continue;
+            }
+
+            let name = path.file_stem().unwrap().to_str().unwrap();
+            // a broken file gets both tests, which fail with a proper message
+            let table = fs::read_to_string(&path).unwrap().parse::<Table>().ok();
+
+            for part in 1..=2 {
+                let key = format!("part_{}", part);
+
+                if table.as_ref().is_none_or(|table| table.contains_key(&key)) {
+                    let test_name = format!(
+                        "{}_example_{}_part_{}",
+                        day_name,
+                        name.replace(|c: char| !c.is_ascii_alphanumeric(), "_"),
+                        part
+                    );
+
+                    tests += &format!(
+                        "#[test]\nfn {}() {{\n    check({}, {}, {:?}, {});\n}}\n\n",
+                        test_name, year, day, name, part
+                    );
+                }
+            }
+        }
+    }
+
+    tests
+}
+
+// `name.txt` → (name, false), `name.txt.enc` → (name, true)
+fn input_name(path: &Path) -> Option<(&str, bool)> {
+    let file_name = path.file_name()?.to_str()?;
+
+    match file_name.strip_suffix(".txt.enc") {
+        Some(name) => Some((name, true)),
+        None => Some((file_name.strip_suffix(".txt")?, false)),
+    }
+}
+
+// encrypted inputs stay encrypted in the binary, and are decrypted when they're read
+fn embedded_input(year: u16, day: u8, name: Option<&str>, encrypted: bool, path: &Path) -> String {
+    format!(
+        "    ({}, {}, {:?}, {}, include_bytes!({:?})),\n",
+        year,
+        day,
+        name,
+        encrypted,
+        path.display().to_string()
+    )
+}
+
+// a plain input wins over an encrypted one of the same name, like in src/input
+fn push_input(
+    inputs: &mut Vec<(Option<String>, bool, PathBuf)>,
+    name: Option<&str>,
+    encrypted: bool,
+    path: PathBuf,
+) {
+    let name = name.map(str::to_string);
+
+    match inputs.iter().position(|(n, ..)| *n == name) {
+        Some(i) if !encrypted => inputs[i] = (name, encrypted, path),
+        Some(_) => {}
+        None => inputs.push((name, encrypted, path)),
+    }
+}
+
+// the inputs the `embed-inputs` feature compiles in, for src/input
+fn embedded_inputs(manifest_dir: &Path) -> String {
+    let mut inputs = String::new();
+
+    println!("cargo:rerun-if-env-changed=AOC_INPUT_DIR");
+
+    if env::var_os("CARGO_FEATURE_EMBED_INPUTS").is_some() {
+        let dir = env::var_os("AOC_INPUT_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| manifest_dir.join("inputs"));
+
+        println!("cargo:rerun-if-changed={}", dir.display());
+
+        let mut days = vec![];
+
+        for path in sorted_entries(&dir) {
+            let (day_name, encrypted) = match input_name(&path) {
+                Some((day_name, encrypted)) => (day_name, Some(encrypted)),
+                None if path.is_dir() => {
+                    let Some(day_name) = path.file_name().and_then(|name| name.to_str()) else {
+                        continue;
+                    };
+
+                    (day_name, None)
+                }
+                None => continue,
+            };
+            let Some((year, day)) =
+                parse_day_name(day_name).filter(|&(year, _)| year_enabled(year))
+            else {
+                continue;
+            };
+
+            if days.last().is_none_or(|&(y, d, _)| (y, d) != (year, day)) {
+                days.push((year, day, vec![]));
+            }
+
+            let day_inputs = &mut days.last_mut().unwrap().2;
+
+            match encrypted {
+                Some(encrypted) => push_input(day_inputs, None, encrypted, path.clone()),
+                None => {
+                    for named in sorted_entries(&path) {
+                        if let Some((name, encrypted)) = input_name(&named) {
+                            push_input(day_inputs, Some(name), encrypted, named.clone());
+                        }
+                    }
+                }
+            }
+        }
+
+        for (year, day, mut day_inputs) in days {
+            // the default input first, like src/input
+            day_inputs.sort_by(|a, b| a.0.cmp(&b.0));
+
+            for (name, encrypted, path) in day_inputs {
+                inputs += &embedded_input(year, day, name.as_deref(), encrypted, &path);
+            }
+        }
+    }
+
+    format!(
+        "// (year, day, name, encrypted, input)\npub const EMBEDDED: &[(u16, u8, Option<&str>, bool, &[u8])] = &[\n{}];\n",
+        inputs
+    )
+}
+
+fn main() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+
+    fs::write(out_dir.join("examples.rs"), example_tests(&manifest_dir)).unwrap();
+    fs::write(out_dir.join("inputs.rs"), embedded_inputs(&manifest_dir)).unwrap();
+}
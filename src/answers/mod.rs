@@ -0,0 +1,112 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+// accepted answers live in the data directory, see Config::data_dir, in answers/yearYYYY_dayDD.txt,
+// or answers/yearYYYY_dayDD/<name>.txt for named inputs
+// line 1 is the part 1 answer, line 2 is the part 2 answer, either may be missing
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    Missing,
+}
+
+pub fn answers_path(data_dir: &Path, year: &str, day: &str, name: Option<&str>) -> PathBuf {
+    let dir = data_dir.join("answers");
+
+    match name {
+        Some(name) => dir.join(format!("year{}_day{}/{}.txt", year, day, name)),
+        None => dir.join(format!("year{}_day{}.txt", year, day)),
+    }
+}
+
+pub fn read_answers(data_dir: &Path, year: &str, day: &str, name: Option<&str>) -> Answers {
+    match fs::read_to_string(answers_path(data_dir, year, day, name)) {
+        Ok(contents) => parse_answers(&contents),
+        Err(_) => Answers::default(),
+    }
+}
+
+// an empty line is a missing answer, e.g. when only part 2 is still unsolved
+pub fn parse_answers(contents: &str) -> Answers {
+    let mut lines = contents
+        .lines()
+        .map(|line| line.trim())
+        .map(|line| (!line.is_empty()).then(|| line.to_string()));
+
+    Answers {
+        part_1: lines.next().flatten(),
+        part_2: lines.next().flatten(),
+    }
+}
+
+pub fn write_answers(
+    data_dir: &Path,
+    year: &str,
+    day: &str,
+    answers: &Answers,
+) -> Result<(), String> {
+    let path = answers_path(data_dir, year, day, None);
+    let mut contents = answers.part_1.clone().unwrap_or_default() + "\n";
+
+    if let Some(part_2) = &answers.part_2 {
+        contents += &(part_2.clone() + "\n");
+    }
+
+    fs::write(&path, contents).map_err(|e| format!("Could not write {}: {}", path.display(), e))
+}
+
+pub fn check(expected: Option<&str>, actual: &str) -> Verdict {
+    match expected {
+        None => Verdict::Missing,
+        Some(expected) if expected == actual => Verdict::Pass,
+        Some(_) => Verdict::Fail,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        assert_eq!(
+            parse_answers("35\n46\n"),
+            Answers {
+                part_1: Some("35".to_string()),
+                part_2: Some("46".to_string())
+            }
+        );
+        assert_eq!(
+            parse_answers(" 35 \r\n"),
+            Answers {
+                part_1: Some("35".to_string()),
+                part_2: None
+            }
+        );
+        assert_eq!(
+            parse_answers("\n46\n"),
+            Answers {
+                part_1: None,
+                part_2: Some("46".to_string())
+            }
+        );
+        assert_eq!(parse_answers(""), Answers::default());
+    }
+
+    #[test]
+    fn test_check() {
+        assert_eq!(check(Some("35"), "35"), Verdict::Pass);
+        assert_eq!(check(Some("35"), "36"), Verdict::Fail);
+        assert_eq!(check(Some("35"), ""), Verdict::Fail);
+        assert_eq!(check(None, "35"), Verdict::Missing);
+    }
+}
@@ -0,0 +1,220 @@
+use std::{
+    fs,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::solution::Day;
+
+// upper bound on samples per phase, so sub-microsecond parts don't collect millions of them
+const MAX_RUNS: usize = 10_000;
+
+#[derive(Clone, Copy, Debug)]
+pub enum Budget {
+    Runs(usize),
+    Time(Duration),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub runs: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+    pub p95_ns: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DayBench {
+    pub year: u16,
+    pub day: u8,
+    pub parse: Stats,
+    pub part_1: Stats,
+    // None for days without a part 2
+    pub part_2: Option<Stats>,
+}
+
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct BenchReport {
+    pub days: Vec<DayBench>,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Stats {
+        samples.sort();
+
+        let nanos = |d: Duration| d.as_nanos() as u64;
+        let runs = samples.len();
+        let p95_idx = ((runs as f64 * 0.95).ceil() as usize).clamp(1, runs) - 1;
+
+        Stats {
+            runs,
+            min_ns: nanos(samples[0]),
+            median_ns: nanos(samples[runs / 2]),
+            mean_ns: nanos(samples.iter().sum::<Duration>() / runs as u32),
+            p95_ns: nanos(samples[p95_idx]),
+        }
+    }
+}
+
+// runs `f` once untimed to warm up, then samples it until the budget is used up
+fn sample<T>(budget: Budget, mut f: impl FnMut() -> T) -> Stats {
+    std::hint::black_box(f());
+
+    let mut samples: Vec<Duration> = vec![];
+    let started = Instant::now();
+
+    loop {
+        let start = Instant::now();
+        let value = std::hint::black_box(f());
+        samples.push(start.elapsed());
+        // dropping a parsed input can take a while, and isn't part of parsing it
+        drop(value);
+
+        let done = match budget {
+            Budget::Runs(runs) => samples.len() >= runs,
+            Budget::Time(time) => started.elapsed() >= time,
+        };
+
+        if done || samples.len() >= MAX_RUNS {
+            break;
+        }
+    }
+
+    Stats::from_samples(samples)
+}
+
+pub fn bench_day(day: &Day, input: &str, budget: Budget) -> DayBench {
+    let parsed = day.parse(input);
+    let params = day.default_params();
+
+    DayBench {
+        year: day.year,
+        day: day.day,
+        parse: sample(budget, || day.parse(input)),
+        part_1: sample(budget, || day.part_1(&parsed, &params)),
+        part_2: day
+            .has_part_2
+            .then(|| sample(budget, || day.part_2(&parsed, &params))),
+    }
+}
+
+impl BenchReport {
+    pub fn load(path: &Path) -> Result<BenchReport, String> {
+        let json = fs::read_to_string(path)
+            .map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+
+        serde_json::from_str(&json)
+            .map_err(|e| format!("Invalid bench file {}: {}", path.display(), e))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self).unwrap();
+
+        fs::write(path, json + "\n")
+            .map_err(|e| format!("Could not write {}: {}", path.display(), e))
+    }
+
+    pub fn find(&self, year: u16, day: u8) -> Option<&DayBench> {
+        self.days.iter().find(|d| d.year == year && d.day == day)
+    }
+}
+
+// relative change of the median, in percent (positive means slower)
+pub fn median_change(baseline: &Stats, current: &Stats) -> f64 {
+    (current.median_ns as f64 - baseline.median_ns as f64) / baseline.median_ns.max(1) as f64
+        * 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    fn stats(median_ns: u64) -> Stats {
+        Stats {
+            runs: 1,
+            min_ns: median_ns,
+            median_ns,
+            mean_ns: median_ns,
+            p95_ns: median_ns,
+        }
+    }
+
+    #[test]
+    fn test_from_samples() {
+        // 20 samples, so p95 is the 19th smallest
+        let samples = (1..=20).rev().map(Duration::from_nanos).collect();
+
+        assert_eq!(
+            Stats::from_samples(samples),
+            Stats {
+                runs: 20,
+                min_ns: 1,
+                median_ns: 11,
+                mean_ns: 10,
+                p95_ns: 19,
+            }
+        );
+        assert_eq!(
+            Stats::from_samples(vec![Duration::from_nanos(7)]),
+            Stats {
+                runs: 1,
+                min_ns: 7,
+                median_ns: 7,
+                mean_ns: 7,
+                p95_ns: 7,
+            }
+        );
+        assert_eq!(
+            Stats::from_samples([3, 1, 2].map(Duration::from_nanos).to_vec()).median_ns,
+            2
+        );
+    }
+
+    #[test]
+    fn test_median_change() {
+        assert_eq!(median_change(&stats(100), &stats(110)), 10.0);
+        assert_eq!(median_change(&stats(200), &stats(150)), -25.0);
+        // a baseline of 0ns doesn't divide by zero
+        assert_eq!(median_change(&stats(0), &stats(3)), 300.0);
+    }
+
+    #[test]
+    fn test_report_file() {
+        let path = env::temp_dir().join(format!("aoc_rust_bench_{}.json", std::process::id()));
+        let report = BenchReport {
+            days: vec![
+                DayBench {
+                    year: 2023,
+                    day: 5,
+                    parse: stats(10),
+                    part_1: stats(20),
+                    part_2: Some(stats(30)),
+                },
+                DayBench {
+                    year: 2023,
+                    day: 25,
+                    parse: stats(10),
+                    part_1: stats(20),
+                    part_2: None,
+                },
+            ],
+        };
+
+        report.save(&path).unwrap();
+
+        let loaded = BenchReport::load(&path).unwrap();
+
+        assert_eq!(loaded, report);
+        assert_eq!(loaded.find(2023, 25), Some(&report.days[1]));
+        assert_eq!(loaded.find(2023, 6), None);
+
+        fs::remove_file(&path).unwrap();
+
+        assert!(BenchReport::load(&path).is_err());
+    }
+}
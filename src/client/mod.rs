@@ -0,0 +1,79 @@
+use std::{fs, path::Path};
+
+use crate::config::Config;
+
+// https://www.reddit.com/r/adventofcode/wiki/faqs/automation
+const USER_AGENT: &str = "github.com/NikolaIliev/aoc_rust";
+
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Fetched {
+    // the input was already on disk, nothing was requested
+    Cached,
+    Downloaded,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: Option<&str>) -> Client {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.map(str::to_string),
+        }
+    }
+
+    pub fn from_config(config: &Config) -> Client {
+        Client::new(&config.base_url(), config.session().as_deref())
+    }
+
+    fn session(&self) -> Result<&str, String> {
+        self.session
+            .as_deref()
+            .ok_or("No session token, set AOC_SESSION or `session` in aoc.toml".to_string())
+    }
+
+    pub fn get(&self, path: &str) -> Result<String, String> {
+        let url = format!("{}{}", self.base_url, path);
+
+        ureq::get(&url)
+            .set("User-Agent", USER_AGENT)
+            .set("Cookie", &format!("session={}", self.session()?))
+            .call()
+            .map_err(|e| format!("GET {} failed: {}", url, e))?
+            .into_string()
+            .map_err(|e| format!("GET {} failed: {}", url, e))
+    }
+
+    pub fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<String, String> {
+        let url = format!("{}{}", self.base_url, path);
+
+        ureq::post(&url)
+            .set("User-Agent", USER_AGENT)
+            .set("Cookie", &format!("session={}", self.session()?))
+            .send_form(form)
+            .map_err(|e| format!("POST {} failed: {}", url, e))?
+            .into_string()
+            .map_err(|e| format!("POST {} failed: {}", url, e))
+    }
+
+    // never re-downloads an input that's already on disk - empty files (from `new`) don't count
+    pub fn fetch_input(&self, year: u16, day: u8, path: &Path) -> Result<Fetched, String> {
+        if fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0) {
+            return Ok(Fetched::Cached);
+        }
+
+        let input = self.get(&format!("/{}/day/{}/input", year, day))?;
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|e| format!("Could not create {}: {}", dir.display(), e))?;
+        }
+
+        fs::write(path, input).map_err(|e| format!("Could not write {}: {}", path.display(), e))?;
+
+        Ok(Fetched::Downloaded)
+    }
+}
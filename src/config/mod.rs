@@ -0,0 +1,136 @@
+use std::{env, fs, path::PathBuf};
+
+use serde::Deserialize;
+
+use crate::crypt::Key;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+// local settings, read from aoc.toml in the data directory (not committed)
+//
+// every setting can be overridden with an env var, e.g. AOC_SESSION for `session`
+#[derive(Debug, Default, Deserialize)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+    pub input_dir: Option<String>,
+    pub strict_input: Option<bool>,
+    pub key_file: Option<String>,
+    pub data_dir: Option<String>,
+}
+
+// AOC_DATA_DIR, or the working directory - where aoc.toml is looked for
+fn default_data_dir() -> PathBuf {
+    env::var("AOC_DATA_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_default()
+}
+
+impl Config {
+    // AOC_CONFIG, or aoc.toml in the data directory
+    pub fn load() -> Result<Config, String> {
+        let path = env::var("AOC_CONFIG").unwrap_or_else(|_| {
+            default_data_dir()
+                .join("aoc.toml")
+                .to_string_lossy()
+                .to_string()
+        });
+
+        let Ok(contents) = fs::read_to_string(&path) else {
+            return Ok(Config::default());
+        };
+
+        toml::from_str(&contents).map_err(|e| format!("Invalid config {}: {}", path, e))
+    }
+
+    pub fn session(&self) -> Option<String> {
+        env::var("AOC_SESSION")
+            .ok()
+            .or_else(|| self.session.clone())
+            .map(|session| session.trim().to_string())
+            .filter(|session| !session.is_empty())
+    }
+
+    pub fn base_url(&self) -> String {
+        env::var("AOC_BASE_URL")
+            .ok()
+            .or_else(|| self.base_url.clone())
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string())
+            .trim_end_matches('/')
+            .to_string()
+    }
+
+    // where inputs/, answers/, guesses/ and history.jsonl live, the working directory by default
+    // so the binary works wherever it's run from - relative input_dir and key_file are relative
+    // to it too
+    pub fn data_dir(&self) -> PathBuf {
+        env::var("AOC_DATA_DIR")
+            .ok()
+            .or_else(|| self.data_dir.clone())
+            .map(PathBuf::from)
+            .unwrap_or_default()
+    }
+
+    pub fn input_dir(&self) -> PathBuf {
+        env::var("AOC_INPUT_DIR")
+            .ok()
+            .or_else(|| self.input_dir.clone())
+            .map(|dir| self.data_dir().join(dir))
+            .unwrap_or_else(|| self.data_dir().join("inputs"))
+    }
+
+    // refuse inputs with unexpected characters instead of running the solutions on them
+    pub fn strict_input(&self) -> bool {
+        env::var("AOC_STRICT_INPUT")
+            .ok()
+            .map(|strict| strict == "1" || strict == "true")
+            .or(self.strict_input)
+            .unwrap_or(false)
+    }
+
+    // where `encrypt` keeps the key for encrypted inputs
+    pub fn key_file(&self) -> PathBuf {
+        env::var("AOC_KEY_FILE")
+            .ok()
+            .or_else(|| self.key_file.clone())
+            .map(|file| self.data_dir().join(file))
+            .unwrap_or_else(|| self.data_dir().join("aoc.key"))
+    }
+
+    // AOC_INPUT_KEY wins over the key file, None if there's neither
+    pub fn input_key(&self) -> Result<Option<Key>, String> {
+        if let Ok(hex) = env::var("AOC_INPUT_KEY") {
+            return Key::from_hex(&hex)
+                .map(Some)
+                .map_err(|e| format!("AOC_INPUT_KEY: {}", e));
+        }
+
+        let path = self.key_file();
+
+        match fs::read_to_string(&path) {
+            Ok(hex) => Key::from_hex(&hex)
+                .map(Some)
+                .map_err(|e| format!("{}: {}", path.display(), e)),
+            Err(_) => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relative_paths() {
+        let config = Config {
+            data_dir: Some("/data".to_string()),
+            input_dir: Some("my_inputs".to_string()),
+            key_file: Some("/keys/aoc.key".to_string()),
+            ..Config::default()
+        };
+
+        assert_eq!(config.input_dir(), PathBuf::from("/data/my_inputs"));
+        assert_eq!(config.key_file(), PathBuf::from("/keys/aoc.key"));
+        assert_eq!(Config::default().key_file(), PathBuf::from("aoc.key"));
+    }
+}
@@ -0,0 +1,119 @@
+use std::{
+    fmt::Write,
+    path::{Path, PathBuf},
+};
+
+use chacha20poly1305::{
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+    ChaCha20Poly1305, Nonce,
+};
+
+// encrypted inputs are this, then a random 12 byte nonce, then the ChaCha20-Poly1305 ciphertext
+const MAGIC: &[u8] = b"aoc-input-v1\n";
+const NONCE_LEN: usize = 12;
+
+pub const EXTENSION: &str = "enc";
+
+// 32 random bytes, written as 64 hex digits to the key file or AOC_INPUT_KEY
+#[derive(Clone, PartialEq)]
+pub struct Key([u8; 32]);
+
+impl Key {
+    pub fn generate() -> Key {
+        Key(ChaCha20Poly1305::generate_key(&mut OsRng).into())
+    }
+
+    pub fn from_hex(hex: &str) -> Result<Key, String> {
+        let hex = hex.trim();
+
+        if hex.len() != 64 || !hex.chars().all(|ch| ch.is_ascii_hexdigit()) {
+            return Err("The input key should be 64 hex digits".to_string());
+        }
+
+        let mut key = [0; 32];
+
+        for (i, byte) in key.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).unwrap();
+        }
+
+        Ok(Key(key))
+    }
+
+    pub fn to_hex(&self) -> String {
+        self.0.iter().fold(String::new(), |mut hex, byte| {
+            write!(hex, "{:02x}", byte).unwrap();
+            hex
+        })
+    }
+}
+
+// `year2023_day05.txt` → `year2023_day05.txt.enc`
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(".");
+    path.push(EXTENSION);
+
+    PathBuf::from(path)
+}
+
+pub fn is_encrypted_path(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == EXTENSION)
+}
+
+pub fn encrypt(key: &Key, plaintext: &str) -> Vec<u8> {
+    let cipher = ChaCha20Poly1305::new(&key.0.into());
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = cipher.encrypt(&nonce, plaintext.as_bytes()).unwrap();
+
+    [MAGIC, nonce.as_slice(), &ciphertext].concat()
+}
+
+pub fn decrypt(key: &Key, data: &[u8]) -> Result<String, String> {
+    let Some(data) = data.strip_prefix(MAGIC) else {
+        return Err("Not an encrypted input".to_string());
+    };
+
+    if data.len() < NONCE_LEN {
+        return Err("Truncated encrypted input".to_string());
+    }
+
+    let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+    let plaintext = ChaCha20Poly1305::new(&key.0.into())
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| "Wrong key, or the encrypted input is corrupted".to_string())?;
+
+    String::from_utf8(plaintext).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encrypt() {
+        let key = Key::generate();
+        let encrypted = encrypt(&key, "1abc2\npqr3stu8vwx\n");
+
+        assert!(encrypted.starts_with(MAGIC));
+        assert_ne!(encrypted, encrypt(&key, "1abc2\npqr3stu8vwx\n"));
+        assert_eq!(
+            decrypt(&key, &encrypted),
+            Ok("1abc2\npqr3stu8vwx\n".to_string())
+        );
+        assert!(decrypt(&Key::generate(), &encrypted).is_err());
+        assert!(decrypt(&key, b"1abc2").is_err());
+    }
+
+    #[test]
+    fn test_key_hex() {
+        let key = Key::generate();
+
+        assert!(Key::from_hex(&key.to_hex()) == Ok(key));
+        assert!(Key::from_hex("abc").is_err());
+        assert!(Key::from_hex(&"g".repeat(64)).is_err());
+        assert_eq!(
+            encrypted_path(Path::new("inputs/year2023_day05.txt")),
+            Path::new("inputs/year2023_day05.txt.enc")
+        );
+    }
+}
@@ -1,4 +1,12 @@
-#[derive(Copy, Clone, Debug)]
+use std::{
+    ops::{BitAnd, BitOr, BitOrAssign},
+    str::FromStr,
+};
+
+use crate::{ivec2::IVec2, uvec2::UVec2};
+
+// a step on a grid, with y growing downwards like row indices
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Direction {
     Up,
     Down,
@@ -10,6 +18,18 @@ pub enum Direction {
     DownLeft,
 }
 
+// clockwise from Up, so turning is moving along it
+const CLOCKWISE: [Direction; 8] = [
+    Direction::Up,
+    Direction::UpRight,
+    Direction::Right,
+    Direction::DownRight,
+    Direction::Down,
+    Direction::DownLeft,
+    Direction::Left,
+    Direction::UpLeft,
+];
+
 impl Direction {
     pub fn all() -> &'static [Direction] {
         &[
@@ -23,4 +43,390 @@ impl Direction {
             Direction::DownLeft,
         ]
     }
+
+    // clockwise from Up, in `index` order
+    pub fn cardinal() -> &'static [Direction] {
+        &[
+            Direction::Up,
+            Direction::Right,
+            Direction::Down,
+            Direction::Left,
+        ]
+    }
+
+    // clockwise from UpRight, in `index` order
+    pub fn diagonal() -> &'static [Direction] {
+        &[
+            Direction::UpRight,
+            Direction::DownRight,
+            Direction::DownLeft,
+            Direction::UpLeft,
+        ]
+    }
+
+    // 0..4 for the cardinal directions and 4..8 for the diagonal ones, so per direction data
+    // fits in a [T; 4] on days without diagonals
+    pub fn index(self) -> usize {
+        match self {
+            Direction::Up => 0,
+            Direction::Right => 1,
+            Direction::Down => 2,
+            Direction::Left => 3,
+            Direction::UpRight => 4,
+            Direction::DownRight => 5,
+            Direction::DownLeft => 6,
+            Direction::UpLeft => 7,
+        }
+    }
+
+    pub fn from_index(index: usize) -> Option<Direction> {
+        Direction::cardinal()
+            .iter()
+            .chain(Direction::diagonal())
+            .nth(index)
+            .copied()
+    }
+
+    pub fn is_cardinal(self) -> bool {
+        self.index() < 4
+    }
+
+    fn turn(self, eighths: usize) -> Direction {
+        let i = CLOCKWISE.iter().position(|&d| d == self).unwrap();
+
+        CLOCKWISE[(i + eighths) % 8]
+    }
+
+    pub fn turn_right(self) -> Direction {
+        self.turn(2)
+    }
+
+    pub fn turn_left(self) -> Direction {
+        self.turn(6)
+    }
+
+    pub fn turn_right_45(self) -> Direction {
+        self.turn(1)
+    }
+
+    pub fn turn_left_45(self) -> Direction {
+        self.turn(7)
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.turn(4)
+    }
+
+    pub fn delta(self) -> IVec2 {
+        match self {
+            Direction::Up => IVec2::new(0, -1),
+            Direction::Down => IVec2::new(0, 1),
+            Direction::Left => IVec2::new(-1, 0),
+            Direction::Right => IVec2::new(1, 0),
+            Direction::UpRight => IVec2::new(1, -1),
+            Direction::UpLeft => IVec2::new(-1, -1),
+            Direction::DownRight => IVec2::new(1, 1),
+            Direction::DownLeft => IVec2::new(-1, 1),
+        }
+    }
+}
+
+// `^>v<`, `UDLR`, `NESW` (north is up) and `0123` for right, down, left and up like in 2023
+// day 18's colours
+impl TryFrom<char> for Direction {
+    type Error = String;
+
+    fn try_from(ch: char) -> Result<Direction, String> {
+        match ch {
+            '^' | 'U' | 'N' | '3' => Ok(Direction::Up),
+            '>' | 'R' | 'E' | '0' => Ok(Direction::Right),
+            'v' | 'D' | 'S' | '1' => Ok(Direction::Down),
+            '<' | 'L' | 'W' | '2' => Ok(Direction::Left),
+            ch => Err(format!("Unknown direction {:?}", ch)),
+        }
+    }
+}
+
+// a single character like TryFrom<char>, or NE, SE, SW or NW
+impl FromStr for Direction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Direction, String> {
+        let mut chars = s.chars();
+
+        match (chars.next(), chars.next(), chars.next()) {
+            (Some(ch), None, _) => Direction::try_from(ch),
+            (Some('N'), Some('E'), None) => Ok(Direction::UpRight),
+            (Some('S'), Some('E'), None) => Ok(Direction::DownRight),
+            (Some('S'), Some('W'), None) => Ok(Direction::DownLeft),
+            (Some('N'), Some('W'), None) => Ok(Direction::UpLeft),
+            _ => Err(format!("Unknown direction {:?}", s)),
+        }
+    }
+}
+
+// any of the eight directions, a bit per `index`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DirectionSet(u8);
+
+impl DirectionSet {
+    pub const EMPTY: DirectionSet = DirectionSet(0);
+
+    fn bit(direction: Direction) -> u8 {
+        1 << direction.index()
+    }
+
+    // false if it was already in the set
+    pub fn insert(&mut self, direction: Direction) -> bool {
+        let inserted = !self.contains(direction);
+        self.0 |= DirectionSet::bit(direction);
+
+        inserted
+    }
+
+    pub fn remove(&mut self, direction: Direction) {
+        self.0 &= !DirectionSet::bit(direction);
+    }
+
+    pub fn contains(self, direction: Direction) -> bool {
+        self.0 & DirectionSet::bit(direction) != 0
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn union(self, other: DirectionSet) -> DirectionSet {
+        DirectionSet(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: DirectionSet) -> DirectionSet {
+        DirectionSet(self.0 & other.0)
+    }
+
+    // in `index` order
+    pub fn iter(self) -> impl Iterator<Item = Direction> {
+        (0..8)
+            .filter_map(Direction::from_index)
+            .filter(move |&direction| self.contains(direction))
+    }
+}
+
+impl BitOr for DirectionSet {
+    type Output = DirectionSet;
+
+    fn bitor(self, other: DirectionSet) -> DirectionSet {
+        self.union(other)
+    }
+}
+
+impl BitOrAssign for DirectionSet {
+    fn bitor_assign(&mut self, other: DirectionSet) {
+        *self = self.union(other);
+    }
+}
+
+impl BitAnd for DirectionSet {
+    type Output = DirectionSet;
+
+    fn bitand(self, other: DirectionSet) -> DirectionSet {
+        self.intersection(other)
+    }
+}
+
+impl From<Direction> for DirectionSet {
+    fn from(direction: Direction) -> DirectionSet {
+        DirectionSet(DirectionSet::bit(direction))
+    }
+}
+
+impl FromIterator<Direction> for DirectionSet {
+    fn from_iter<I: IntoIterator<Item = Direction>>(directions: I) -> DirectionSet {
+        let mut set = DirectionSet::EMPTY;
+        set.extend(directions);
+
+        set
+    }
+}
+
+impl Extend<Direction> for DirectionSet {
+    fn extend<I: IntoIterator<Item = Direction>>(&mut self, directions: I) {
+        for direction in directions {
+            self.insert(direction);
+        }
+    }
+}
+
+// a DirectionSet per cell of a grid, for "have I been here going this way?"
+#[derive(Clone, Debug, PartialEq)]
+pub struct DirectionGrid {
+    pub width: usize,
+    pub height: usize,
+    cells: Vec<DirectionSet>,
+}
+
+impl DirectionGrid {
+    pub fn new(size: UVec2) -> DirectionGrid {
+        DirectionGrid {
+            width: size.x,
+            height: size.y,
+            cells: vec![DirectionSet::EMPTY; size.x * size.y],
+        }
+    }
+
+    fn cell(&self, pos: UVec2) -> usize {
+        assert!(pos.x < self.width && pos.y < self.height);
+
+        pos.y * self.width + pos.x
+    }
+
+    pub fn get(&self, pos: UVec2) -> DirectionSet {
+        self.cells[self.cell(pos)]
+    }
+
+    // false if the cell had been visited in this direction already
+    pub fn insert(&mut self, pos: UVec2, direction: Direction) -> bool {
+        let cell = self.cell(pos);
+
+        self.cells[cell].insert(direction)
+    }
+
+    pub fn contains(&self, pos: UVec2, direction: Direction) -> bool {
+        self.get(pos).contains(direction)
+    }
+
+    // cells visited in any direction
+    pub fn visited(&self) -> usize {
+        self.cells.iter().filter(|set| !set.is_empty()).count()
+    }
+
+    pub fn clear(&mut self) {
+        self.cells.fill(DirectionSet::EMPTY);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::UpLeft.turn_right(), Direction::UpRight);
+        assert_eq!(Direction::Up.turn_right_45(), Direction::UpRight);
+        assert_eq!(Direction::Up.turn_left_45(), Direction::UpLeft);
+        assert_eq!(Direction::DownRight.opposite(), Direction::UpLeft);
+
+        for &direction in Direction::all() {
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert_eq!(direction.opposite().opposite(), direction);
+            assert_eq!(direction.opposite().delta(), -direction.delta());
+            assert_eq!(
+                direction.turn_right_45().turn_right_45(),
+                direction.turn_right()
+            );
+            assert_eq!(Direction::from_index(direction.index()), Some(direction));
+        }
+    }
+
+    #[test]
+    fn test_subsets() {
+        assert_eq!(Direction::Right.delta(), IVec2::new(1, 0));
+        assert_eq!(Direction::UpLeft.delta(), IVec2::new(-1, -1));
+        assert!(Direction::cardinal()
+            .iter()
+            .all(|&d| d.is_cardinal() && d.delta().manhattan(IVec2::ZERO) == 1));
+        assert!(Direction::diagonal()
+            .iter()
+            .all(|&d| !d.is_cardinal() && d.delta().manhattan(IVec2::ZERO) == 2));
+        assert_eq!(Direction::from_index(8), None);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            "^>v<"
+                .chars()
+                .map(Direction::try_from)
+                .collect::<Result<Vec<_>, _>>(),
+            Ok(Direction::cardinal().to_vec())
+        );
+        assert_eq!(
+            ["U", "R", "D", "L"].map(|s| s.parse::<Direction>().unwrap()),
+            ["N", "E", "S", "W"].map(|s| s.parse::<Direction>().unwrap()),
+        );
+        assert_eq!(
+            "0123"
+                .chars()
+                .map(|ch| Direction::try_from(ch).unwrap())
+                .collect::<Vec<_>>(),
+            vec![
+                Direction::Right,
+                Direction::Down,
+                Direction::Left,
+                Direction::Up
+            ]
+        );
+        assert_eq!("SW".parse(), Ok(Direction::DownLeft));
+        assert!("x".parse::<Direction>().is_err());
+        assert!("NEE".parse::<Direction>().is_err());
+        assert!("".parse::<Direction>().is_err());
+    }
+
+    #[test]
+    fn test_direction_set() {
+        let mut set = DirectionSet::EMPTY;
+
+        assert!(set.insert(Direction::Left));
+        assert!(set.insert(Direction::UpRight));
+        assert!(!set.insert(Direction::Left));
+        assert!(set.contains(Direction::Left));
+        assert!(!set.contains(Direction::Right));
+        assert_eq!(set.len(), 2);
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            vec![Direction::Left, Direction::UpRight]
+        );
+
+        set.remove(Direction::UpRight);
+
+        assert_eq!(set, DirectionSet::from(Direction::Left));
+
+        let cardinal = Direction::cardinal()
+            .iter()
+            .copied()
+            .collect::<DirectionSet>();
+
+        assert_eq!(cardinal.len(), 4);
+        assert_eq!(cardinal & set, set);
+        assert_eq!(
+            (cardinal | Direction::diagonal().iter().copied().collect()).len(),
+            8
+        );
+        assert!(DirectionSet::EMPTY.is_empty());
+    }
+
+    #[test]
+    fn test_direction_grid() {
+        let mut grid = DirectionGrid::new(UVec2::new(3, 2));
+
+        assert!(grid.insert(UVec2::new(2, 1), Direction::Up));
+        assert!(grid.insert(UVec2::new(2, 1), Direction::Down));
+        assert!(!grid.insert(UVec2::new(2, 1), Direction::Up));
+        assert!(grid.insert(UVec2::new(0, 0), Direction::Up));
+        assert!(grid.contains(UVec2::new(2, 1), Direction::Down));
+        assert!(!grid.contains(UVec2::new(1, 1), Direction::Down));
+        assert_eq!(grid.get(UVec2::new(2, 1)).len(), 2);
+        assert_eq!(grid.visited(), 2);
+
+        grid.clear();
+
+        assert_eq!(grid.visited(), 0);
+    }
 }
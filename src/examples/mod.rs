@@ -0,0 +1,249 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use itertools::Itertools;
+use toml::{Table, Value};
+
+use crate::{
+    normalize::normalize,
+    puzzle::Puzzle,
+    solution::{Day, Params},
+};
+
+// examples live in examples/yearYYYY_dayDD/<name>.txt, and what they should produce in
+// <name>.toml next to them:
+//
+//     part_1 = "102"
+//     part_2 = "94"
+//
+//     [params]
+//     steps = "6"
+//
+// either part may be missing, and build.rs generates one test per example and expected part
+#[derive(Debug, Default, PartialEq)]
+pub struct Expected {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+    pub params: Vec<(String, String)>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub expected: Expected,
+}
+
+pub fn examples_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("examples")
+}
+
+pub fn day_dir(root: &Path, year: u16, day: u8) -> PathBuf {
+    root.join(format!("year{}_day{:02}", year, day))
+}
+
+// numbers and strings alike, `part_1 = 102` is as good as `part_1 = "102"`
+fn value_string(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        value => value.to_string(),
+    }
+}
+
+pub fn parse_expected(contents: &str) -> Result<Expected, String> {
+    let table = contents.parse::<Table>().map_err(|e| e.to_string())?;
+    let params = match table.get("params") {
+        Some(Value::Table(params)) => params
+            .iter()
+            .map(|(name, value)| (name.clone(), value_string(value)))
+            .collect(),
+        Some(_) => return Err("params should be a table".to_string()),
+        None => vec![],
+    };
+
+    Ok(Expected {
+        part_1: table.get("part_1").map(value_string),
+        part_2: table.get("part_2").map(value_string),
+        params,
+    })
+}
+
+pub fn load_example(dir: &Path, name: &str) -> Result<Example, String> {
+    let input_path = dir.join(format!("{}.txt", name));
+    let expected_path = dir.join(format!("{}.toml", name));
+
+    let input = fs::read_to_string(&input_path)
+        .map_err(|e| format!("Could not read {}: {}", input_path.display(), e))?;
+    let expected = match fs::read_to_string(&expected_path) {
+        Ok(contents) => parse_expected(&contents)
+            .map_err(|e| format!("Invalid {}: {}", expected_path.display(), e))?,
+        Err(_) => Expected::default(),
+    };
+
+    Ok(Example {
+        name: name.to_string(),
+        // examples are normalised like inputs, but don't warn about it
+        input: normalize(&input).0,
+        expected,
+    })
+}
+
+// every example of a day, `2` before `10`
+pub fn find_examples(dir: &Path) -> Result<Vec<Example>, String> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Ok(vec![]);
+    };
+
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .filter_map(|path| Some(path.file_stem()?.to_str()?.to_string()))
+        .sorted_by_key(|name| (name.len(), name.clone()))
+        .map(|name| load_example(dir, &name))
+        .collect()
+}
+
+// Ok if the part produces the expected answer, or if there's no expected answer
+pub fn check(day: &Day, example: &Example, part: u8) -> Result<(), String> {
+    let expected = match part {
+        1 => &example.expected.part_1,
+        _ => &example.expected.part_2,
+    };
+
+    let Some(expected) = expected else {
+        return Ok(());
+    };
+
+    let params = Params::new(day.params, &example.expected.params)?;
+    let parsed = day.parse(&example.input);
+    let answer = match part {
+        1 => day.part_1(&parsed, &params),
+        _ => day.part_2(&parsed, &params),
+    };
+
+    if answer != *expected {
+        return Err(format!(
+            "year {} day {} example {} part {}: expected {}, got {}",
+            day.year,
+            day.day_str(),
+            example.name,
+            part,
+            expected,
+            answer
+        ));
+    }
+
+    Ok(())
+}
+
+// the puzzle's examples as 1.txt, 2.txt, ... - existing inputs are kept, and answers are only
+// added to the expected ones, so this can run again once part 2 is unlocked
+pub fn write_examples(dir: &Path, puzzle: &Puzzle) -> Result<Vec<PathBuf>, String> {
+    let mut written = vec![];
+
+    for (i, part) in puzzle.parts.iter().enumerate() {
+        let name = (part.example + 1).to_string();
+        let input_path = dir.join(format!("{}.txt", name));
+        let expected_path = dir.join(format!("{}.toml", name));
+
+        fs::create_dir_all(dir)
+            .map_err(|e| format!("Could not create {}: {}", dir.display(), e))?;
+
+        if !input_path.exists() {
+            fs::write(&input_path, puzzle.examples[part.example].clone() + "\n")
+                .map_err(|e| format!("Could not write {}: {}", input_path.display(), e))?;
+            written.push(input_path);
+        }
+
+        let Some(answer) = &part.answer else {
+            continue;
+        };
+
+        let mut expected = fs::read_to_string(&expected_path)
+            .unwrap_or_default()
+            .parse::<Table>()
+            .map_err(|e| format!("Invalid {}: {}", expected_path.display(), e))?;
+        let key = format!("part_{}", i + 1);
+
+        if expected.contains_key(&key) {
+            continue;
+        }
+
+        expected.insert(key, Value::String(answer.clone()));
+        fs::write(&expected_path, expected.to_string())
+            .map_err(|e| format!("Could not write {}: {}", expected_path.display(), e))?;
+        written.push(expected_path);
+    }
+
+    Ok(written.into_iter().unique().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_expected() {
+        assert_eq!(
+            parse_expected("part_1 = 102\n\n[params]\nsteps = \"6\"\n"),
+            Ok(Expected {
+                part_1: Some("102".to_string()),
+                part_2: None,
+                params: vec![("steps".to_string(), "6".to_string())],
+            })
+        );
+        assert!(parse_expected("params = 6").is_err());
+    }
+
+    // checks against 2015 day 1
+    #[cfg(feature = "year2015")]
+    #[test]
+    fn test_examples() {
+        use crate::{puzzle::PartExample, solutions};
+        use std::env;
+
+        let dir = env::temp_dir().join(format!("aoc_rust_examples_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        let mut puzzle = Puzzle {
+            markdown: String::new(),
+            examples: vec!["(((".to_string(), "())(".to_string()],
+            parts: vec![PartExample {
+                example: 0,
+                answer: Some("3".to_string()),
+            }],
+        };
+
+        write_examples(&dir, &puzzle).unwrap();
+
+        // part 2 unlocked, with an example of its own
+        puzzle.parts.push(PartExample {
+            example: 1,
+            answer: Some("3".to_string()),
+        });
+        write_examples(&dir, &puzzle).unwrap();
+
+        let examples = find_examples(&dir).unwrap();
+        let day = solutions::find(2015, 1).unwrap();
+
+        assert_eq!(examples.len(), 2);
+        assert_eq!(examples[0].input, "(((");
+        assert_eq!(examples[0].expected.part_1.as_deref(), Some("3"));
+        assert_eq!(examples[1].expected.part_2.as_deref(), Some("3"));
+        assert_eq!(check(day, &examples[0], 1), Ok(()));
+        assert_eq!(check(day, &examples[1], 2), Ok(()));
+        assert_eq!(check(day, &examples[1], 1), Ok(()));
+
+        fs::write(dir.join("1.toml"), "part_1 = 4").unwrap();
+
+        assert_eq!(
+            check(day, &find_examples(&dir).unwrap()[0], 1),
+            Err("year 2015 day 01 example 1 part 1: expected 4, got 3".to_string())
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
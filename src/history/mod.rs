@@ -0,0 +1,288 @@
+use std::{
+    env, fs,
+    io::Write,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use crate::report::Record;
+
+// one timed part, appended to history.jsonl (one JSON object per line) after every run
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    // unix seconds
+    pub timestamp: u64,
+    pub machine: String,
+    #[serde(flatten)]
+    pub record: Record,
+}
+
+// consecutive runs of one part at one revision
+#[derive(Debug, PartialEq)]
+pub struct RevisionStats {
+    pub revision: String,
+    // of the first run at this revision
+    pub timestamp: u64,
+    pub runs: usize,
+    pub median_ns: u64,
+    pub answer: String,
+    // median change against the previous revision, in percent
+    pub change: Option<f64>,
+    pub slower: bool,
+    pub answer_changed: bool,
+}
+
+// timings are only comparable on the same machine, and answers only on the same input
+#[derive(Debug, PartialEq)]
+pub struct Trend {
+    pub part: String,
+    pub input: Option<String>,
+    pub machine: String,
+    pub revisions: Vec<RevisionStats>,
+}
+
+pub fn history_path(data_dir: &Path) -> PathBuf {
+    data_dir.join("history.jsonl")
+}
+
+pub fn machine_id() -> String {
+    env::var("HOSTNAME")
+        .or_else(|_| env::var("COMPUTERNAME"))
+        .ok()
+        .or_else(|| fs::read_to_string("/etc/hostname").ok())
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+pub fn entries(records: Vec<Record>) -> Vec<Entry> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let machine = machine_id();
+
+    records
+        .into_iter()
+        .map(|record| Entry {
+            timestamp,
+            machine: machine.clone(),
+            record,
+        })
+        .collect()
+}
+
+pub fn append(path: &Path, entries: &[Entry]) -> Result<(), String> {
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| format!("Could not open {}: {}", path.display(), e))?;
+
+    let lines = entries
+        .iter()
+        .map(|entry| serde_json::to_string(entry).unwrap() + "\n")
+        .join("");
+
+    file.write_all(lines.as_bytes())
+        .map_err(|e| format!("Could not write {}: {}", path.display(), e))
+}
+
+pub fn load(path: &Path) -> Result<Vec<Entry>, String> {
+    let Ok(contents) = fs::read_to_string(path) else {
+        return Ok(vec![]);
+    };
+
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .enumerate()
+        .map(|(i, line)| {
+            serde_json::from_str(line)
+                .map_err(|e| format!("Invalid history {} line {}: {}", path.display(), i + 1, e))
+        })
+        .collect()
+}
+
+fn median(mut values: Vec<u64>) -> u64 {
+    values.sort();
+
+    values[values.len() / 2]
+}
+
+// per part, input and machine, how the median time and the answer changed from one revision to
+// the next - a revision counts as slower if its median is more than `threshold` percent higher
+pub fn trends(entries: &[Entry], year: u16, day: u8, threshold: f64) -> Vec<Trend> {
+    let groups = entries
+        .iter()
+        .filter(|entry| entry.record.year == year && entry.record.day == day)
+        .sorted_by_key(|entry| entry.timestamp)
+        .into_group_map_by(|entry| {
+            (
+                entry.record.part.clone(),
+                entry.record.input.clone(),
+                entry.record.input_hash.clone(),
+                entry.machine.clone(),
+            )
+        });
+
+    let mut trends = vec![];
+
+    for ((part, input, _, machine), entries) in
+        groups
+            .into_iter()
+            .sorted_by_key(|((part, input, ..), entries)| {
+                (entries[0].timestamp, part.clone(), input.clone())
+            })
+    {
+        let mut revisions: Vec<RevisionStats> = vec![];
+
+        for (revision, runs) in &entries
+            .iter()
+            .group_by(|entry| entry.record.revision.clone())
+        {
+            let runs = runs.collect_vec();
+            let median_ns = median(runs.iter().map(|entry| entry.record.duration_ns).collect());
+            let answer = runs.last().unwrap().record.answer.clone();
+            let previous = revisions.last();
+            let change = previous.map(|previous| {
+                (median_ns as f64 - previous.median_ns as f64) / previous.median_ns.max(1) as f64
+                    * 100.0
+            });
+
+            revisions.push(RevisionStats {
+                revision,
+                timestamp: runs[0].timestamp,
+                runs: runs.len(),
+                median_ns,
+                change,
+                slower: change.is_some_and(|change| change > threshold),
+                answer_changed: previous.is_some_and(|previous| previous.answer != answer),
+                answer,
+            });
+        }
+
+        trends.push(Trend {
+            part,
+            input,
+            machine,
+            revisions,
+        });
+    }
+
+    trends
+}
+
+// `YYYY-MM-DD HH:MM` in UTC, see http://howardhinnant.github.io/date_algorithms.html
+pub fn format_timestamp(timestamp: u64) -> String {
+    let days = (timestamp / 86400) as i64 + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days.rem_euclid(146097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + (month <= 2) as i64;
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}",
+        year,
+        month,
+        day,
+        timestamp % 86400 / 3600,
+        timestamp % 3600 / 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(timestamp: u64, revision: &str, part: &str, answer: &str, duration_ns: u64) -> Entry {
+        Entry {
+            timestamp,
+            machine: "box".to_string(),
+            record: Record {
+                year: 2023,
+                day: 5,
+                input: None,
+                part: part.to_string(),
+                answer: answer.to_string(),
+                duration_ns,
+                input_hash: "abc".to_string(),
+                revision: revision.to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_trends() {
+        let entries = vec![
+            entry(1, "aaa", "1", "35", 100),
+            entry(1, "aaa", "2", "46", 500),
+            entry(2, "aaa", "1", "35", 120),
+            entry(3, "aaa", "1", "35", 110),
+            entry(4, "bbb", "1", "35", 200),
+            entry(5, "ccc", "1", "36", 105),
+        ];
+
+        let trends = trends(&entries, 2023, 5, 10.0);
+
+        assert_eq!(trends.len(), 2);
+        assert_eq!(trends[0].part, "1");
+        assert_eq!(
+            trends[0]
+                .revisions
+                .iter()
+                .map(|r| (
+                    r.revision.as_str(),
+                    r.runs,
+                    r.median_ns,
+                    r.slower,
+                    r.answer_changed
+                ))
+                .collect_vec(),
+            vec![
+                ("aaa", 3, 110, false, false),
+                ("bbb", 1, 200, true, false),
+                ("ccc", 1, 105, false, true),
+            ]
+        );
+        assert_eq!(trends[1].part, "2");
+        assert_eq!(trends[1].revisions.len(), 1);
+        assert!(super::trends(&entries, 2023, 6, 10.0).is_empty());
+    }
+
+    #[test]
+    fn test_history_file() {
+        let path = env::temp_dir().join(format!("aoc_rust_history_{}.jsonl", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(load(&path), Ok(vec![]));
+
+        append(&path, &[entry(1, "aaa", "1", "35", 100)]).unwrap();
+        append(&path, &[entry(2, "bbb", "1", "35", 90)]).unwrap();
+
+        assert_eq!(
+            load(&path),
+            Ok(vec![
+                entry(1, "aaa", "1", "35", 100),
+                entry(2, "bbb", "1", "35", 90)
+            ])
+        );
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00");
+        assert_eq!(format_timestamp(1701388800 + 3723), "2023-12-01 01:02");
+        assert_eq!(format_timestamp(1709164800), "2024-02-29 00:00");
+    }
+}
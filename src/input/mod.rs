@@ -0,0 +1,410 @@
+use std::{
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    config::Config,
+    crypt::{self, Key},
+    normalize::{self, Changes},
+};
+
+// one puzzle input for a day - the default inputs/yearYYYY_dayDD.txt, or a named extra one in
+// inputs/yearYYYY_dayDD/<name>.txt, e.g. someone else's input to check the solution against
+//
+// either can be encrypted as <file>.txt.enc instead, see `encrypt_inputs`
+#[derive(Clone, Debug, PartialEq)]
+pub struct Input {
+    pub name: Option<String>,
+    pub path: PathBuf,
+}
+
+// an input as the solutions get it, after normalize
+#[derive(Debug, PartialEq)]
+pub struct LoadedInput {
+    pub name: Option<String>,
+    pub text: String,
+    pub changes: Changes,
+}
+
+// where a day's input comes from, `--input -` reads it from stdin
+#[derive(Clone, Debug, PartialEq)]
+pub enum Source {
+    Dir(PathBuf),
+    File(PathBuf),
+    Stdin,
+    // compiled in with the `embed-inputs` feature, see build.rs
+    Embedded,
+}
+
+impl Source {
+    pub fn from_arg(arg: Option<&Path>, config: &Config) -> Source {
+        match arg {
+            Some(path) if path == Path::new("-") => Source::Stdin,
+            Some(path) => Source::File(path.to_path_buf()),
+            None if cfg!(feature = "embed-inputs") => Source::Embedded,
+            None => Source::Dir(config.input_dir()),
+        }
+    }
+}
+
+mod embedded {
+    include!(concat!(env!("OUT_DIR"), "/inputs.rs"));
+}
+
+pub fn input_path(dir: &Path, year: u16, day: u8) -> PathBuf {
+    dir.join(format!("year{}_day{:02}.txt", year, day))
+}
+
+// `name.txt` or `name.txt.enc` → `name`
+fn input_name(path: &Path) -> Option<String> {
+    let file_name = path.file_name()?.to_str()?;
+    let name = file_name.strip_suffix(&format!(".txt.{}", crypt::EXTENSION));
+
+    name.or_else(|| file_name.strip_suffix(".txt"))
+        .map(str::to_string)
+}
+
+// the plain file if it exists, otherwise the encrypted one
+fn existing_input(path: PathBuf) -> Option<PathBuf> {
+    let encrypted = crypt::encrypted_path(&path);
+
+    match path.exists() {
+        true => Some(path),
+        false => encrypted.exists().then_some(encrypted),
+    }
+}
+
+// the default input first, then the named ones sorted by name - missing files are left out
+pub fn find_inputs(dir: &Path, year: u16, day: u8) -> Vec<Input> {
+    let default = input_path(dir, year, day);
+    let mut inputs = vec![];
+
+    if let Some(path) = existing_input(default.clone()) {
+        inputs.push(Input { name: None, path });
+    }
+
+    let named_dir = default.with_extension("");
+    let mut names = fs::read_dir(&named_dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| input_name(&entry.ok()?.path()))
+        .collect::<Vec<String>>();
+
+    names.sort();
+    names.dedup();
+    inputs.extend(names.into_iter().filter_map(|name| {
+        let path = existing_input(named_dir.join(format!("{}.txt", name)))?;
+
+        Some(Input {
+            name: Some(name),
+            path,
+        })
+    }));
+
+    inputs
+}
+
+fn read_file(path: &Path) -> Result<String, String> {
+    if crypt::is_encrypted_path(path) {
+        return read_encrypted(path, &input_key()?);
+    }
+
+    fs::read_to_string(path).map_err(|e| format!("Could not read input {}: {}", path.display(), e))
+}
+
+fn read_encrypted(path: &Path, key: &Key) -> Result<String, String> {
+    let data =
+        fs::read(path).map_err(|e| format!("Could not read input {}: {}", path.display(), e))?;
+
+    crypt::decrypt(key, &data).map_err(|e| format!("Could not decrypt {}: {}", path.display(), e))
+}
+
+// only loaded once there's an encrypted input to read
+fn input_key() -> Result<Key, String> {
+    let config = Config::load()?;
+
+    config.input_key()?.ok_or_else(|| {
+        format!(
+            "Encrypted inputs need a key, set AOC_INPUT_KEY or put it in {}",
+            config.key_file().display()
+        )
+    })
+}
+
+fn read_stdin() -> Result<String, String> {
+    let mut input = String::new();
+
+    io::stdin()
+        .read_to_string(&mut input)
+        .map_err(|e| format!("Could not read input from stdin: {}", e))?;
+
+    Ok(input)
+}
+
+// strict mode refuses inputs with characters that don't belong in a puzzle input
+fn load(
+    name: Option<String>,
+    raw: &str,
+    origin: &str,
+    strict: bool,
+) -> Result<LoadedInput, String> {
+    let (text, changes) = normalize::normalize(raw);
+
+    if strict {
+        normalize::check_characters(&text)
+            .map_err(|e| format!("Invalid input {}: {}", origin, e))?;
+    }
+
+    Ok(LoadedInput {
+        name,
+        text,
+        changes,
+    })
+}
+
+// every input of a day, the default one (if there is one) first
+pub fn read_inputs(
+    source: &Source,
+    year: u16,
+    day: u8,
+    strict: bool,
+) -> Result<Vec<LoadedInput>, String> {
+    let dir = match source {
+        Source::Stdin => return Ok(vec![load(None, &read_stdin()?, "from stdin", strict)?]),
+        Source::File(path) => {
+            let origin = path.display().to_string();
+
+            return Ok(vec![load(None, &read_file(path)?, &origin, strict)?]);
+        }
+        Source::Embedded => {
+            let inputs = embedded::EMBEDDED
+                .iter()
+                .filter(|&&(y, d, ..)| y == year && d == day)
+                .map(|&(_, _, name, encrypted, data)| {
+                    let origin = format!("{} (embedded)", name.unwrap_or("default"));
+                    let raw = match encrypted {
+                        true => crypt::decrypt(&input_key()?, data)
+                            .map_err(|e| format!("Could not decrypt {}: {}", origin, e))?,
+                        false => String::from_utf8_lossy(data).to_string(),
+                    };
+
+                    load(name.map(str::to_string), &raw, &origin, strict)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            if inputs.is_empty() {
+                return Err(format!(
+                    "No input for year {} day {:02} was embedded at build time",
+                    year, day
+                ));
+            }
+
+            return Ok(inputs);
+        }
+        Source::Dir(dir) => dir,
+    };
+    let inputs = find_inputs(dir, year, day);
+
+    if inputs.is_empty() {
+        return Err(format!(
+            "No input for year {} day {:02}, expected {}",
+            year,
+            day,
+            input_path(dir, year, day).display()
+        ));
+    }
+
+    inputs
+        .into_iter()
+        .map(|input| {
+            let origin = input.path.display().to_string();
+
+            load(input.name, &read_file(&input.path)?, &origin, strict)
+        })
+        .collect()
+}
+
+// every plain or every encrypted input file in the input directory, named ones included
+fn input_files(dir: &Path, encrypted: bool) -> Vec<PathBuf> {
+    let files = |dir: &Path| {
+        fs::read_dir(dir)
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .collect::<Vec<PathBuf>>()
+    };
+    let mut paths = files(dir)
+        .into_iter()
+        .flat_map(|path| match path.is_dir() {
+            true => files(&path),
+            false => vec![path],
+        })
+        .filter(|path| input_name(path).is_some() && crypt::is_encrypted_path(path) == encrypted)
+        .collect::<Vec<PathBuf>>();
+
+    paths.sort();
+
+    paths
+}
+
+// replaces every plain input with an encrypted one, returns the new files
+pub fn encrypt_inputs(dir: &Path, key: &Key) -> Result<Vec<PathBuf>, String> {
+    input_files(dir, false)
+        .into_iter()
+        .map(|path| {
+            let encrypted = crypt::encrypted_path(&path);
+
+            fs::write(&encrypted, crypt::encrypt(key, &read_file(&path)?))
+                .map_err(|e| format!("Could not write {}: {}", encrypted.display(), e))?;
+            fs::remove_file(&path)
+                .map_err(|e| format!("Could not remove {}: {}", path.display(), e))?;
+
+            Ok(encrypted)
+        })
+        .collect()
+}
+
+// the other way around, nothing is written unless every input decrypts with the key
+pub fn decrypt_inputs(dir: &Path, key: &Key) -> Result<Vec<PathBuf>, String> {
+    let decrypted = input_files(dir, true)
+        .into_iter()
+        .map(|path| Ok((read_encrypted(&path, key)?, path)))
+        .collect::<Result<Vec<(String, PathBuf)>, String>>()?;
+
+    decrypted
+        .into_iter()
+        .map(|(input, encrypted)| {
+            let path = encrypted.with_extension("");
+
+            fs::write(&path, input)
+                .map_err(|e| format!("Could not write {}: {}", path.display(), e))?;
+            fs::remove_file(&encrypted)
+                .map_err(|e| format!("Could not remove {}: {}", encrypted.display(), e))?;
+
+            Ok(path)
+        })
+        .collect()
+}
+
+// the default input of a day, from the configured input directory or the embedded inputs
+pub fn read_input(year: u16, day: u8) -> Result<String, String> {
+    let source = Source::from_arg(None, &Config::load()?);
+
+    read_inputs(&source, year, day, false)?
+        .into_iter()
+        .find(|input| input.name.is_none())
+        .map(|input| input.text)
+        .ok_or_else(|| format!("No default input for year {} day {:02}", year, day))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    #[test]
+    fn test_read_inputs() {
+        let dir = env::temp_dir().join(format!("aoc_rust_inputs_{}", std::process::id()));
+        let named = dir.join("year2023_day05");
+        fs::create_dir_all(&named).unwrap();
+        fs::write(dir.join("year2023_day05.txt"), "mine\n").unwrap();
+        fs::write(named.join("bob.txt"), "bob's").unwrap();
+        fs::write(named.join("alice.txt"), "alice's\r\n").unwrap();
+        fs::write(named.join("notes.md"), "not an input").unwrap();
+
+        let source = Source::Dir(dir.clone());
+
+        let inputs = read_inputs(&source, 2023, 5, false).unwrap();
+
+        assert_eq!(
+            inputs
+                .iter()
+                .map(|input| (input.name.as_deref(), input.text.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                (None, "mine"),
+                (Some("alice"), "alice's"),
+                (Some("bob"), "bob's")
+            ]
+        );
+        assert!(inputs[0].changes.is_empty());
+        assert_eq!(inputs[1].changes.crlf_lines, 1);
+        assert!(
+            read_inputs(&source, 2023, 6, false).is_err_and(|e| e.contains("year2023_day06.txt"))
+        );
+        assert_eq!(
+            read_inputs(&Source::File(named.join("bob.txt")), 2023, 6, false).map(|inputs| inputs
+                .into_iter()
+                .map(|input| input.text)
+                .collect::<Vec<_>>()),
+            Ok(vec!["bob's".to_string()])
+        );
+
+        fs::write(named.join("bob.txt"), "bob’s").unwrap();
+
+        assert!(read_inputs(&source, 2023, 5, false).is_ok());
+        assert!(read_inputs(&source, 2023, 5, true)
+            .is_err_and(|e| e.contains("bob.txt") && e.contains("column 4")));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_encrypt_inputs() {
+        let dir = env::temp_dir().join(format!("aoc_rust_encrypted_{}", std::process::id()));
+        let named = dir.join("year2023_day05");
+        fs::create_dir_all(&named).unwrap();
+        fs::write(dir.join("year2023_day05.txt"), "mine\n").unwrap();
+        fs::write(named.join("bob.txt"), "bob's").unwrap();
+
+        let key = Key::generate();
+
+        assert_eq!(
+            encrypt_inputs(&dir, &key),
+            Ok(vec![
+                named.join("bob.txt.enc"),
+                dir.join("year2023_day05.txt.enc")
+            ])
+        );
+        assert!(!dir.join("year2023_day05.txt").exists());
+        assert!(!fs::read(named.join("bob.txt.enc"))
+            .unwrap()
+            .windows(5)
+            .any(|w| w == b"bob's"));
+
+        // a plain input next to an encrypted one wins
+        fs::write(named.join("bob.txt"), "bob's, edited").unwrap();
+
+        assert_eq!(
+            find_inputs(&dir, 2023, 5),
+            vec![
+                Input {
+                    name: None,
+                    path: dir.join("year2023_day05.txt.enc")
+                },
+                Input {
+                    name: Some("bob".to_string()),
+                    path: named.join("bob.txt")
+                }
+            ]
+        );
+
+        fs::remove_file(named.join("bob.txt")).unwrap();
+
+        assert!(decrypt_inputs(&dir, &Key::generate()).is_err());
+        assert!(dir.join("year2023_day05.txt.enc").exists());
+        assert_eq!(
+            decrypt_inputs(&dir, &key),
+            Ok(vec![named.join("bob.txt"), dir.join("year2023_day05.txt")])
+        );
+        assert_eq!(fs::read_to_string(named.join("bob.txt")).unwrap(), "bob's");
+        assert!(find_inputs(&dir, 2023, 5)
+            .iter()
+            .all(|input| !crypt::is_encrypted_path(&input.path)));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,230 @@
+use std::{
+    fmt::{self, Display},
+    num::TryFromIntError,
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::uvec2::UVec2;
+
+// a position or a step that can go negative, e.g. a walk away from its start, or a neighbour of a
+// cell at the edge of a grid before it's checked with `in_bounds`
+//
+// same operators as UVec2, ordered by x then y
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct IVec2 {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl IVec2 {
+    pub const ZERO: IVec2 = IVec2 { x: 0, y: 0 };
+
+    pub const fn new(x: isize, y: isize) -> IVec2 {
+        IVec2 { x, y }
+    }
+
+    pub fn checked_add(self, other: IVec2) -> Option<IVec2> {
+        Some(IVec2 {
+            x: self.x.checked_add(other.x)?,
+            y: self.y.checked_add(other.y)?,
+        })
+    }
+
+    pub fn checked_sub(self, other: IVec2) -> Option<IVec2> {
+        Some(IVec2 {
+            x: self.x.checked_sub(other.x)?,
+            y: self.y.checked_sub(other.y)?,
+        })
+    }
+
+    pub fn checked_mul(self, scalar: isize) -> Option<IVec2> {
+        Some(IVec2 {
+            x: self.x.checked_mul(scalar)?,
+            y: self.y.checked_mul(scalar)?,
+        })
+    }
+
+    pub fn wrapping_add(self, other: IVec2) -> IVec2 {
+        IVec2 {
+            x: self.x.wrapping_add(other.x),
+            y: self.y.wrapping_add(other.y),
+        }
+    }
+
+    pub fn wrapping_sub(self, other: IVec2) -> IVec2 {
+        IVec2 {
+            x: self.x.wrapping_sub(other.x),
+            y: self.y.wrapping_sub(other.y),
+        }
+    }
+
+    pub fn wrapping_mul(self, scalar: isize) -> IVec2 {
+        IVec2 {
+            x: self.x.wrapping_mul(scalar),
+            y: self.y.wrapping_mul(scalar),
+        }
+    }
+
+    pub fn manhattan(self, other: IVec2) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev(self, other: IVec2) -> usize {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    // the position in a grid of this size, None if it's outside of it
+    pub fn in_bounds(self, size: UVec2) -> Option<UVec2> {
+        UVec2::try_from(self)
+            .ok()
+            .filter(|pos| pos.x < size.x && pos.y < size.y)
+    }
+}
+
+impl Add for IVec2 {
+    type Output = IVec2;
+
+    fn add(self, other: IVec2) -> IVec2 {
+        IVec2 {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+impl Sub for IVec2 {
+    type Output = IVec2;
+
+    fn sub(self, other: IVec2) -> IVec2 {
+        IVec2 {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+impl Mul<isize> for IVec2 {
+    type Output = IVec2;
+
+    fn mul(self, scalar: isize) -> IVec2 {
+        IVec2 {
+            x: self.x * scalar,
+            y: self.y * scalar,
+        }
+    }
+}
+
+impl Neg for IVec2 {
+    type Output = IVec2;
+
+    fn neg(self) -> IVec2 {
+        IVec2 {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl AddAssign for IVec2 {
+    fn add_assign(&mut self, other: IVec2) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for IVec2 {
+    fn sub_assign(&mut self, other: IVec2) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign<isize> for IVec2 {
+    fn mul_assign(&mut self, scalar: isize) {
+        *self = *self * scalar;
+    }
+}
+
+impl From<(isize, isize)> for IVec2 {
+    fn from((x, y): (isize, isize)) -> IVec2 {
+        IVec2 { x, y }
+    }
+}
+
+impl From<IVec2> for (isize, isize) {
+    fn from(v: IVec2) -> (isize, isize) {
+        (v.x, v.y)
+    }
+}
+
+// fails if either coordinate is negative
+impl TryFrom<IVec2> for UVec2 {
+    type Error = TryFromIntError;
+
+    fn try_from(v: IVec2) -> Result<UVec2, TryFromIntError> {
+        Ok(UVec2::new(v.x.try_into()?, v.y.try_into()?))
+    }
+}
+
+impl TryFrom<UVec2> for IVec2 {
+    type Error = TryFromIntError;
+
+    fn try_from(v: UVec2) -> Result<IVec2, TryFromIntError> {
+        Ok(IVec2::new(v.x.try_into()?, v.y.try_into()?))
+    }
+}
+
+impl Display for IVec2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let mut v = IVec2::new(3, -4);
+
+        assert_eq!(v + IVec2::new(-5, 2), IVec2::new(-2, -2));
+        assert_eq!(v - IVec2::new(5, 2), IVec2::new(-2, -6));
+        assert_eq!(v * -2, IVec2::new(-6, 8));
+        assert_eq!(-v, IVec2::new(-3, 4));
+
+        v += IVec2::new(1, 1);
+        v -= IVec2::new(0, 2);
+        v *= 2;
+
+        assert_eq!(v, IVec2::new(8, -10));
+        assert_eq!(v.checked_add(IVec2::new(isize::MAX, 0)), None);
+        assert_eq!(v.checked_sub(IVec2::new(0, isize::MAX)), None);
+        assert_eq!(v.checked_mul(2), Some(IVec2::new(16, -20)));
+        assert_eq!(
+            IVec2::new(isize::MAX, 0).wrapping_add(IVec2::new(1, 0)),
+            IVec2::new(isize::MIN, 0)
+        );
+        assert_eq!(
+            IVec2::new(isize::MIN, 0).wrapping_sub(IVec2::new(1, 0)),
+            IVec2::new(isize::MAX, 0)
+        );
+        assert_eq!(IVec2::new(1, -2).wrapping_mul(3), IVec2::new(3, -6));
+        assert_eq!(IVec2::new(-1, 6).manhattan(IVec2::new(5, -11)), 23);
+        assert_eq!(IVec2::new(-1, 6).chebyshev(IVec2::new(5, -11)), 17);
+    }
+
+    #[test]
+    fn test_conversions() {
+        let size = UVec2::new(10, 5);
+
+        assert_eq!(IVec2::new(9, 4).in_bounds(size), Some(UVec2::new(9, 4)));
+        assert_eq!(IVec2::new(10, 4).in_bounds(size), None);
+        assert_eq!(IVec2::new(0, 5).in_bounds(size), None);
+        assert_eq!(IVec2::new(-1, 0).in_bounds(size), None);
+        assert_eq!(UVec2::try_from(IVec2::new(2, 7)), Ok(UVec2::new(2, 7)));
+        assert!(UVec2::try_from(IVec2::new(2, -7)).is_err());
+        assert_eq!(IVec2::try_from(UVec2::new(2, 7)), Ok(IVec2::new(2, 7)));
+        assert!(IVec2::try_from(UVec2::new(usize::MAX, 0)).is_err());
+        assert_eq!(IVec2::from((-2, 7)).to_string(), "-2,7");
+        assert_eq!(<(isize, isize)>::from(IVec2::new(-2, 7)), (-2, 7));
+    }
+}
@@ -1,14 +1,29 @@
-use std::{fs, path::Path};
+use std::path::{Path, PathBuf};
 
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod config;
+pub mod crypt;
 pub mod direction;
+pub mod examples;
+pub mod history;
+pub mod input;
+pub mod ivec2;
+pub mod memory;
+pub mod normalize;
+pub mod puzzle;
+pub mod report;
+pub mod runner;
+pub mod selector;
+pub mod solution;
+pub mod solutions;
+pub mod submit;
 pub mod traversable_matrix;
 pub mod uvec2;
+pub mod vec3;
+pub mod watch;
 
-pub fn read_input(year: &str, day: &str) -> String {
-    return fs::read_to_string(
-        Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("inputs/year{}_day{}.txt", year, day)),
-    )
-    .unwrap()
-    .trim_end_matches("\n")
-    .to_string();
+pub fn solution_path(year: &str, day: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("src/solutions/year{}_day{}.rs", year, day))
 }
@@ -1,28 +1,282 @@
 use std::{
     env, fs,
-    path::Path,
-    process::{Command, Stdio},
-    thread::sleep,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    process::{self, Command},
     time::Duration,
 };
 
-fn new_day(year: &str, day: &str) {
-    let path_base = Path::new(env!("CARGO_MANIFEST_DIR"));
-    let path_rs = path_base.join(format!("src/bin/year{}_day{}.rs", year, day));
-    let path_input = path_base.join(format!("inputs/year{}_day{}.txt", year, day));
+use aoc_rust::{
+    answers::{self, Verdict},
+    bench::{self, BenchReport, Budget},
+    client::{Client, Fetched},
+    config::Config,
+    crypt::{self, Key},
+    examples, history,
+    input::{self, Source},
+    memory::{self, Allocs, CountingAllocator},
+    puzzle::{self, Puzzle},
+    report::{self, Context, Format, Record},
+    runner::{self, Failure},
+    selector::DaySelector,
+    solution::{self, Day, Params},
+    solution_path, solutions,
+    submit::{self, Outcome},
+    watch::{self, Watcher},
+};
+use clap::{Args, CommandFactory, Parser, Subcommand};
+use clap_complete::Shell;
+use itertools::Itertools;
 
-    if path_rs.exists() || path_input.exists() {
-        println!("Task already exists, exiting without changing anything.");
+// only counts while --alloc is given, otherwise it's the system allocator
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code solutions and the tooling around them")]
+struct Cli {
+    #[command(subcommand)]
+    command: Cmd,
+}
+
+#[derive(Args)]
+struct Selection {
+    /// Only this year, e.g. 2023
+    #[arg(value_parser = clap::value_parser!(u16).range(2015..))]
+    year: Option<u16>,
+    /// Only these days of the year: `5`, `1..=10`, `1..10` or `3,7,12`
+    #[arg(requires = "year")]
+    days: Option<DaySelector>,
+}
+
+#[derive(Args)]
+struct DayArgs {
+    #[arg(value_parser = clap::value_parser!(u16).range(2015..))]
+    year: u16,
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+#[derive(Subcommand)]
+enum Cmd {
+    /// Run days and print their answers
+    Run {
+        #[arg(value_parser = clap::value_parser!(u16).range(2015..))]
+        year: u16,
+        /// `5`, `1..=10`, `1..10` or `3,7,12`, the whole year if omitted
+        days: Option<DaySelector>,
+        /// Read the input from this file instead, `-` for stdin
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Print machine-readable records instead of a table
+        #[arg(long, value_enum)]
+        format: Option<Format>,
+        /// Count allocations of parse and both parts, which makes them slower
+        #[arg(long, conflicts_with = "format")]
+        alloc: bool,
+        /// Override one of the day's params, e.g. `--param steps=6` for an example
+        #[arg(long = "param", value_name = "NAME=VALUE", value_parser = solution::parse_param)]
+        params: Vec<(String, String)>,
+    },
+    /// Run every selected day and print a summary
+    All {
+        #[command(flatten)]
+        selection: Selection,
+        /// Print machine-readable records instead of a table
+        #[arg(long, value_enum)]
+        format: Option<Format>,
+        /// Count allocations of parse and both parts, which makes them slower
+        #[arg(long, conflicts_with = "format")]
+        alloc: bool,
+        /// Give up on a day after this many seconds, 0 to wait forever
+        #[arg(long, default_value_t = 60.0)]
+        timeout: f64,
+    },
+    /// Check answers against the accepted ones in `answers/`
+    Verify {
+        #[command(flatten)]
+        selection: Selection,
+        /// Give up on a day after this many seconds, 0 to wait forever
+        #[arg(long, default_value_t = 60.0)]
+        timeout: f64,
+    },
+    /// Benchmark parse and both parts
+    Bench {
+        #[command(flatten)]
+        selection: Selection,
+        /// Run every phase this many times
+        #[arg(long, conflicts_with = "time")]
+        runs: Option<usize>,
+        /// Run every phase for this many seconds
+        #[arg(long, default_value_t = 1.0)]
+        time: f64,
+        /// Save the results as JSON
+        #[arg(long)]
+        save: Option<PathBuf>,
+        /// Compare against results saved earlier
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// Median slowdown, in percent, reported as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+        /// Print machine-readable records instead of a table
+        #[arg(long, value_enum)]
+        format: Option<Format>,
+    },
+    /// Generate boilerplate for a new day
+    New {
+        #[command(flatten)]
+        day: DayArgs,
+        /// Also download the input and the puzzle description
+        #[arg(long)]
+        fetch: bool,
+        /// Take the description from a saved puzzle page
+        #[arg(long)]
+        html: Option<PathBuf>,
+    },
+    /// Download inputs, existing ones are never downloaded again
+    Fetch {
+        #[arg(value_parser = clap::value_parser!(u16).range(2015..))]
+        year: u16,
+        days: DaySelector,
+    },
+    /// Refresh the Markdown description and examples of a day, e.g. once part 2 is unlocked
+    Puzzle {
+        #[command(flatten)]
+        day: DayArgs,
+        /// Take the description from a saved puzzle page
+        #[arg(long)]
+        html: Option<PathBuf>,
+    },
+    /// Watch files & run the day's tests automatically
+    Test(DayArgs),
+    /// Submit the answer for a part
+    Submit {
+        #[command(flatten)]
+        day: DayArgs,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Read the input from this file instead, `-` for stdin
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Show how a day's timings and answers changed between commits
+    History {
+        #[command(flatten)]
+        day: DayArgs,
+        /// Median slowdown, in percent, reported as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Run one day on the input from stdin and report each phase, for `all` and `verify`
+    #[command(hide = true)]
+    Worker {
+        #[command(flatten)]
+        day: DayArgs,
+        #[arg(long)]
+        alloc: bool,
+    },
+    /// Encrypt the plain inputs in place, creating a key if there's none
+    Encrypt,
+    /// Turn the encrypted inputs back into plain ones
+    Decrypt,
+    /// Print a shell completion script
+    Completions { shell: Shell },
+}
+
+// a new year needs a feature to be compiled, and is compiled by default
+fn register_year(path_base: &Path, year: &str) {
+    let path_manifest = path_base.join("Cargo.toml");
+    let manifest = fs::read_to_string(&path_manifest).unwrap();
+    let feature = format!("year{} = []", year);
 
+    if manifest.lines().any(|l| l == feature) {
         return;
     }
 
-    fs::write(
-        &path_rs,
-        r#"
-use std::time::Instant;
+    let mut lines = manifest.lines().map(str::to_string).collect_vec();
+    let default = lines
+        .iter()
+        .position(|l| l.starts_with("default = ["))
+        .unwrap();
+    lines[default] = lines[default].replacen(']', &format!(", \"year{}\"]", year), 1);
+
+    let last_year = lines.iter().rposition(|l| l.starts_with("year")).unwrap();
+    lines.insert(last_year + 1, feature);
+
+    fs::write(&path_manifest, lines.join("\n") + "\n").unwrap();
+    println!(
+        "Added the year{} feature to {}",
+        year,
+        path_manifest.display()
+    );
+}
 
-use aoc_rust::read_input;
+fn register_day(path_base: &Path, year: &str, day: &str) {
+    let path_mod = path_base.join("src/solutions/mod.rs");
+    let registry = fs::read_to_string(&path_mod).unwrap();
+    let group = format!("    \"year{}\" => [", year);
+    let entry = format!(
+        "        year{}_day{}: {}, {};",
+        year,
+        day,
+        year,
+        day.parse::<u8>().unwrap()
+    );
+
+    let mut lines = registry.lines().collect::<Vec<&str>>();
+    let registry_start = lines.iter().position(|&l| l == "days! {").unwrap();
+    let registry_end = registry_start
+        + lines[registry_start..]
+            .iter()
+            .position(|&l| l == "}")
+            .unwrap();
+
+    // years and the entries in them are kept sorted, so insert before whatever sorts after
+    let group_start = lines[registry_start..registry_end]
+        .iter()
+        .position(|&l| l == group)
+        .map(|i| registry_start + i);
+
+    match group_start {
+        Some(group_start) => {
+            let group_end = group_start
+                + lines[group_start..]
+                    .iter()
+                    .position(|&l| l == "    ]")
+                    .unwrap();
+            let insert_at = lines[group_start + 1..group_end]
+                .iter()
+                .position(|&l| l > entry.as_str())
+                .map_or(group_end, |i| group_start + 1 + i);
+
+            lines.insert(insert_at, &entry);
+        }
+        None => {
+            let insert_at = lines[registry_start + 1..registry_end]
+                .iter()
+                .position(|&l| l.starts_with("    \"") && l > group.as_str())
+                .map_or(registry_end, |i| registry_start + 1 + i);
+
+            lines.splice(insert_at..insert_at, [group.as_str(), &entry, "    ]"]);
+        }
+    }
+
+    fs::write(&path_mod, lines.join("\n") + "\n").unwrap();
+    println!(
+        "Registered year{}_day{} in {}",
+        year,
+        day,
+        path_mod.display()
+    );
+
+    if group_start.is_none() {
+        register_year(path_base, year);
+    }
+}
+
+const TEMPLATE: &str = r#"
+use crate::solution::{Params, Solution};
 
 fn part_1(input: &str) -> String {
     return "".to_string();
@@ -32,120 +286,1069 @@ fn part_2(input: &str) -> String {
     return "".to_string();
 }
 
-fn main() {
-    let input = read_input("YEAR", "DAY");
+pub struct Puzzle;
 
-    let start_part_1 = Instant::now();
-    let part_1_result = part_1(&input);
-    let part_1_time = start_part_1.elapsed();
+impl Solution for Puzzle {
+    type Input = String;
 
-    println!();
-    println!("Part 1: {} ({:?})", part_1_result, part_1_time);
+    fn parse(input: &str) -> String {
+        input.to_string()
+    }
 
-    let start_part_2 = Instant::now();
-    let part_2_result = part_2(&input);
-    let part_2_time = start_part_2.elapsed();
+    fn part_1(input: &String, _: &Params) -> String {
+        part_1(input)
+    }
 
-    println!("Part 2: {} ({:?})", part_2_result, part_2_time);
+    fn part_2(input: &String, _: &Params) -> String {
+        part_2(input)
+    }
 }
+"#;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+// the puzzle page, from `--html <file>` or downloaded with `--fetch`
+fn load_puzzle(year: u16, day: u8, fetch: bool, html: Option<&Path>) -> Option<Puzzle> {
+    let html = match (html, fetch) {
+        (Some(path), _) => fs::read_to_string(path)
+            .map_err(|e| format!("Could not read {}: {}", path.display(), e)),
+        (None, true) => Config::load()
+            .and_then(|config| Client::from_config(&config).get(&format!("/{}/day/{}", year, day))),
+        (None, false) => return None,
+    };
 
-    #[ignore]
-    #[test]
-    fn test_part_1() {
-        let input = r"".trim();
+    match html {
+        Ok(html) => Some(puzzle::parse_puzzle(&html)),
+        Err(e) => {
+            eprintln!("{}", e);
+            None
+        }
+    }
+}
 
-        assert_eq!(part_1(input), "");
+fn save_description(year: &str, day: &str, puzzle: &Puzzle) {
+    match puzzle::write_description(year, day, puzzle) {
+        Ok(path) => println!("Created {}", path.display()),
+        Err(e) => eprintln!("{}", e),
     }
+}
 
-    #[ignore]
-    #[test]
-    fn test_part_2() {
-        let input = r"".trim();
+fn save_examples(year: u16, day: u8, puzzle: &Puzzle) {
+    let dir = examples::day_dir(&examples::examples_dir(), year, day);
 
-        assert_eq!(part_2(input), "");
+    match examples::write_examples(&dir, puzzle) {
+        Ok(paths) => {
+            for path in paths {
+                println!("Created {}", path.display());
+            }
+        }
+        Err(e) => eprintln!("{}", e),
     }
 }
-"#
-        .replace("YEAR", year)
-        .replace("DAY", day)
-        .trim(),
-    )
-    .unwrap();
+
+fn new_day(year_n: u16, day_n: u8, fetch_input: bool, html: Option<&Path>) {
+    let (year, day) = (&year_n.to_string(), &format!("{:02}", day_n));
+    let path_base = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let path_rs = solution_path(year, day);
+    let path_input = input::input_path(&load_config().input_dir(), year_n, day_n);
+
+    if path_rs.exists() || path_input.exists() || crypt::encrypted_path(&path_input).exists() {
+        println!("Task already exists, exiting without changing anything.");
+
+        return;
+    }
+
+    let puzzle = load_puzzle(year_n, day_n, fetch_input, html);
+
+    fs::write(&path_rs, TEMPLATE.trim_start()).unwrap();
 
     println!("Created {}", path_rs.display());
 
+    if let Some(puzzle) = &puzzle {
+        save_description(year, day, puzzle);
+        save_examples(year_n, day_n, puzzle);
+    }
+
+    register_day(path_base, year, day);
+
     fs::write(&path_input, "").unwrap();
     println!("Created {}", path_input.display());
+
+    if fetch_input {
+        fetch(year_n, day_n);
+    }
 }
 
-fn check_cargo_watch_installed() -> bool {
-    return Command::new("which")
-        .args(["cargo-watch"])
-        .status()
-        .unwrap()
-        .success();
+// refreshes the description of an existing day, e.g. once part 2 is unlocked
+fn describe(year: u16, day: u8, html: Option<&Path>) {
+    let Some(puzzle) = load_puzzle(year, day, true, html) else {
+        process::exit(1);
+    };
+
+    save_description(&year.to_string(), &format!("{:02}", day), &puzzle);
+    save_examples(year, day, &puzzle);
+
+    for (i, part) in puzzle.parts.iter().enumerate() {
+        println!(
+            "Part {} example answer: {}",
+            i + 1,
+            part.answer.as_deref().unwrap_or("not found")
+        );
+    }
 }
 
-fn install_cargo_watch() {
-    println!("cargo-watch missing. Installing now...");
-    sleep(Duration::from_secs(3));
+fn fetch(year: u16, day: u8) {
+    let config = load_config();
+    let path = input::input_path(&config.input_dir(), year, day);
 
-    Command::new("cargo")
-        .args(["install", "cargo-watch"])
-        .stdout(Stdio::inherit())
-        .status()
-        .unwrap();
+    let encrypted = crypt::encrypted_path(&path);
+
+    if encrypted.exists() {
+        println!("{} already exists, not downloading", encrypted.display());
 
-    println!("cargo-watch installed. Please run command again");
+        return;
+    }
+
+    let fetched = Client::from_config(&config).fetch_input(year, day, &path);
+
+    match fetched {
+        Ok(Fetched::Cached) => println!("{} already exists, not downloading", path.display()),
+        Ok(Fetched::Downloaded) => println!("Downloaded {}", path.display()),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
 }
 
-fn test(year: &str, day: &str) {
-    if !check_cargo_watch_installed() {
-        install_cargo_watch()
-    } else {
-        Command::new("cargo-watch")
-            .args(["-x", &format!("test --bin year{}_day{}", year, day)])
-            .stdout(Stdio::inherit())
-            .stderr(Stdio::inherit())
-            .status()
+fn encrypt() {
+    let config = load_config();
+    let key = match config.input_key() {
+        Ok(Some(key)) => key,
+        Ok(None) => {
+            let key = Key::generate();
+            let path = config.key_file();
+
+            if let Err(e) = fs::write(&path, key.to_hex() + "\n") {
+                eprintln!("Could not write {}: {}", path.display(), e);
+                process::exit(1);
+            }
+
+            println!(
+                "Created {}, keep a copy somewhere safe: without it the inputs are lost",
+                path.display()
+            );
+
+            key
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
+    };
+
+    migrate_inputs(
+        input::encrypt_inputs(&config.input_dir(), &key),
+        "Encrypted",
+    );
+}
+
+fn decrypt() {
+    let config = load_config();
+    let key = match config.input_key() {
+        Ok(Some(key)) => key,
+        Ok(None) => {
+            eprintln!(
+                "No key to decrypt with, set AOC_INPUT_KEY or put it in {}",
+                config.key_file().display()
+            );
+            process::exit(2);
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
+    };
+
+    migrate_inputs(
+        input::decrypt_inputs(&config.input_dir(), &key),
+        "Decrypted",
+    );
+}
+
+fn migrate_inputs(migrated: Result<Vec<PathBuf>, String>, verb: &str) {
+    match migrated {
+        Ok(paths) if paths.is_empty() => println!("Nothing to do"),
+        Ok(paths) => {
+            for path in &paths {
+                println!("Created {}", path.display());
+            }
+
+            println!("{} {} inputs", verb, paths.len());
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
+}
+
+// reruns the day's tests and examples whenever anything in src/, examples/ or the inputs changes,
+// until interrupted
+fn test(year: u16, day: u8) {
+    let path_base = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let filter = format!("year{}_day{:02}", year, day);
+    let mut watcher = Watcher::new(vec![
+        path_base.join("src"),
+        examples::examples_dir(),
+        load_config().input_dir(),
+    ]);
+
+    loop {
+        let output = Command::new("cargo")
+            .args(["test", "--lib", "--test", "examples", &filter])
+            .current_dir(path_base)
+            .output()
             .unwrap();
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        // clear the screen and move the cursor to the top left
+        print!("\x1b[2J\x1b[H");
+
+        match watch::parse_test_output(&stdout) {
+            Some(summary) if summary.failed == 0 => println!(
+                "PASS {}: {} passed, {} ignored",
+                filter, summary.passed, summary.ignored
+            ),
+            Some(summary) => {
+                for failure in &summary.failures {
+                    println!("FAILED {}", failure.name);
+                    println!("{}", failure.output);
+                    println!();
+                }
+
+                println!(
+                    "FAIL {}: {} passed, {} failed, {} ignored",
+                    filter, summary.passed, summary.failed, summary.ignored
+                );
+            }
+            None => {
+                println!("{}", stderr.trim());
+                println!();
+                println!("BUILD FAILED {}", filter);
+            }
+        }
+
+        println!();
+        println!("Watching src/, examples/ and inputs for changes...");
+
+        watcher.wait(Duration::from_millis(300));
     }
 }
 
-fn run(year: &str, day: &str) {
-    Command::new("cargo")
-        .args([
-            "run",
-            "--release",
-            "--bin",
-            &format!("year{}_day{}", year, day),
-        ])
-        .stdout(Stdio::inherit())
-        .stderr(Stdio::inherit())
-        .status()
-        .unwrap();
+fn revision() -> String {
+    report::git_revision(Path::new(env!("CARGO_MANIFEST_DIR")))
 }
 
-fn main() {
-    let args: Vec<String> = env::args().skip(1).collect();
+fn print_records(records: &[Record], format: Format) {
+    print!("{}", report::format_records(records, format));
+}
 
-    if args.len() < 2 {
-        eprintln!("Expected two arguments - year and day");
+// a failure to record the history shouldn't fail the run
+//
+// counting allocations makes everything slower, so those timings aren't comparable with the others
+fn save_history(records: &[Record]) {
+    if memory::is_enabled() {
         return;
     }
 
-    let year = &args[0];
-    let day = &args[1];
-    let command = if args.len() == 3 { &args[2] } else { "" };
+    if let Err(e) = history::append(
+        &history::history_path(&load_config().data_dir()),
+        &history::entries(records.to_vec()),
+    ) {
+        eprintln!("{}", e);
+    }
+}
+
+fn alloc_suffix(allocs: &Option<Allocs>) -> String {
+    allocs.map_or(String::new(), |allocs| format!(", {}", allocs))
+}
+
+fn alloc_cell(allocs: &Option<Allocs>) -> String {
+    allocs.map_or(String::new(), |allocs| allocs.to_string())
+}
+
+fn enable_alloc_counting() {
+    if !memory::enable() {
+        eprintln!("Allocation counting isn't available");
+        process::exit(2);
+    }
+}
+
+fn run(days: Vec<&Day>, source: &Source, format: Option<Format>, overrides: &[(String, String)]) {
+    let revision = revision();
+    let mut records = vec![];
+    let mut missing_input = false;
+
+    for day in days {
+        let params = Params::new(day.params, overrides).unwrap_or_else(|e| {
+            eprintln!("Year {} day {}: {}", day.year, day.day_str(), e);
+            process::exit(2);
+        });
+        let inputs = match load_inputs(day, source) {
+            Ok(inputs) => inputs,
+            Err(e) => {
+                eprintln!("{}", e);
+                missing_input = true;
+                continue;
+            }
+        };
+
+        for (name, input) in inputs {
+            let result = runner::run(day, &input, &params);
+            records.extend(context(day, &name, &input, &revision).run_records(&result));
+
+            if format.is_some() {
+                continue;
+            }
+
+            println!();
+            println!("{} day {}", day.year, day_label(day, &name));
+
+            println!(
+                "Parse: {:?}{}",
+                result.parse.time,
+                alloc_suffix(&result.parse.allocs)
+            );
+            println!(
+                "Part 1: {} ({:?}{})",
+                result.part_1.answer,
+                result.part_1.time,
+                alloc_suffix(&result.part_1.allocs)
+            );
+
+            if let Some(part_2) = &result.part_2 {
+                println!(
+                    "Part 2: {} ({:?}{})",
+                    part_2.answer,
+                    part_2.time,
+                    alloc_suffix(&part_2.allocs)
+                );
+            }
+        }
+    }
+
+    // overridden params are for examples, their answers aren't the day's
+    if overrides.is_empty() {
+        save_history(&records);
+    }
+
+    if let Some(format) = format {
+        print_records(&records, format);
+    }
+
+    if missing_input {
+        process::exit(1);
+    }
+}
+
+fn print_table(header: &[&str], rows: &[Vec<String>]) {
+    let widths = (0..header.len())
+        .map(|col| {
+            rows.iter()
+                .map(|row| row[col].chars().count())
+                .chain([header[col].len()])
+                .max()
+                .unwrap()
+        })
+        .collect_vec();
+
+    let format_row = |row: Vec<&str>| {
+        row.iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{:<width$}", cell))
+            .join(" | ")
+            .trim_end_matches([' ', '|'])
+            .to_string()
+    };
+
+    println!("{}", format_row(header.to_vec()));
+    println!(
+        "{}",
+        widths.iter().map(|&width| "-".repeat(width)).join("-+-")
+    );
+
+    for row in rows {
+        println!("{}", format_row(row.iter().map(String::as_str).collect()));
+    }
+}
+
+// registered days matching the year and day filters, exits if there are none
+fn select_days(year: Option<u16>, days: Option<&DaySelector>) -> Vec<&'static Day> {
+    let selected = solutions::DAYS
+        .iter()
+        .filter(|d| year.is_none_or(|year| d.year == year))
+        .filter(|d| days.is_none_or(|days| days.contains(d.day)))
+        .collect_vec();
+
+    if selected.is_empty() {
+        eprintln!("No registered solutions match");
+        process::exit(2);
+    }
+
+    selected
+}
+
+fn find_day(year: u16, day: u8) -> &'static Day {
+    let Some(day) = solutions::find(year, day) else {
+        eprintln!("No solution registered for year {} day {:02}", year, day);
+        process::exit(2);
+    };
+
+    day
+}
+
+fn load_config() -> Config {
+    Config::load().unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(2);
+    })
+}
+
+fn default_source() -> Source {
+    Source::from_arg(None, &load_config())
+}
+
+// prints a warning for every input normalize had to change
+fn load_inputs(day: &Day, source: &Source) -> Result<Vec<(Option<String>, String)>, String> {
+    let inputs = runner::load_inputs(day, source, load_config().strict_input())?
+        .into_iter()
+        .inspect(|input| {
+            if !input.changes.is_empty() {
+                eprintln!(
+                    "Warning: input for year {} day {}: {}",
+                    day.year,
+                    day_label(day, &input.name),
+                    input.changes
+                );
+            }
+        })
+        .map(|input| (input.name, input.text))
+        .collect_vec();
+
+    Ok(inputs)
+}
+
+fn context<'a>(
+    day: &Day,
+    name: &'a Option<String>,
+    input: &'a str,
+    revision: &'a str,
+) -> Context<'a> {
+    Context {
+        year: day.year,
+        day: day.day,
+        input_name: name.as_deref(),
+        input,
+        revision,
+    }
+}
+
+// named inputs get their own row, e.g. `05 (alice)`
+fn day_label(day: &Day, name: &Option<String>) -> String {
+    match name {
+        Some(name) => format!("{} ({})", day.day_str(), name),
+        None => day.day_str(),
+    }
+}
+
+fn timeout_from_secs(secs: f64) -> Option<Duration> {
+    (secs > 0.0).then(|| Duration::from_secs_f64(secs))
+}
+
+// `aoc worker` for the day, so that a day that runs out of time can be killed
+fn worker_command(day: &Day) -> Command {
+    let mut command = Command::new(env::current_exe().unwrap());
+    command.args(["worker", &day.year_str(), &day.day.to_string()]);
+
+    if memory::is_enabled() {
+        command.arg("--alloc");
+    }
+
+    command
+}
+
+fn worker(day: &Day, alloc: bool) {
+    let mut input = String::new();
+
+    if let Err(e) = io::stdin().read_to_string(&mut input) {
+        eprintln!("Could not read the input from stdin: {}", e);
+        process::exit(2);
+    }
+
+    if alloc {
+        enable_alloc_counting();
+    }
+
+    runner::run_worker(day, &input, &mut io::stdout().lock());
+}
+
+// a phase that panics or runs out of time is reported, the other phases and days still run
+fn all(days: Vec<&'static Day>, format: Option<Format>, timeout: Option<Duration>) {
+    let header = if memory::is_enabled() {
+        vec![
+            "Year", "Day", "Parse", "Allocs", "Part 1", "Time", "Allocs", "Part 2", "Time",
+            "Allocs",
+        ]
+    } else {
+        vec!["Year", "Day", "Parse", "Part 1", "Time", "Part 2", "Time"]
+    };
+    let source = default_source();
+    let revision = revision();
+    let mut records = vec![];
+    let mut rows: Vec<Vec<String>> = vec![];
+    let mut total_time = Duration::ZERO;
+
+    for day in days {
+        let Ok(inputs) = load_inputs(day, &source) else {
+            rows.push(vec![
+                day.year_str(),
+                day.day_str(),
+                "skipped (no input)".to_string(),
+            ]);
+            continue;
+        };
+
+        for (name, input) in inputs {
+            let result = runner::run_isolated(day, worker_command(day), &input, timeout);
+            total_time += result.total_time();
+            records.extend(context(day, &name, &input, &revision).isolated_records(&result));
+
+            let mut row = vec![day.year_str(), day_label(day, &name)];
+
+            match &result.parse {
+                Ok(parse) => row.push(format!("{:.2?}", parse.time)),
+                Err(failure) => row.push(failure.to_string()),
+            }
+
+            if memory::is_enabled() {
+                row.push(alloc_cell(
+                    &result.parse.as_ref().ok().and_then(|parse| parse.allocs),
+                ));
+            }
+
+            for part in [Some(&result.part_1), result.part_2.as_ref()] {
+                match part {
+                    Some(Ok(part)) => {
+                        row.extend([part.answer.clone(), format!("{:.2?}", part.time)]);
+
+                        if memory::is_enabled() {
+                            row.push(alloc_cell(&part.allocs));
+                        }
+                    }
+                    Some(Err(failure)) => {
+                        row.push(failure.to_string());
+                        row.resize(
+                            row.len() + if memory::is_enabled() { 2 } else { 1 },
+                            String::new(),
+                        );
+                    }
+                    // days without a part 2 get a `-` instead
+                    None => row.push("-".to_string()),
+                }
+            }
+
+            rows.push(row);
+        }
+    }
+
+    save_history(&records);
+
+    if let Some(format) = format {
+        return print_records(&records, format);
+    }
+
+    for row in &mut rows {
+        row.resize(header.len(), String::new());
+    }
+
+    println!();
+    print_table(&header, &rows);
+    println!();
+    println!("Total: {:.2?}", total_time);
+}
+
+// exits with a non-zero code if any answer doesn't match the stored one
+fn verify(days: Vec<&'static Day>, timeout: Option<Duration>) {
+    let source = default_source();
+    let data_dir = load_config().data_dir();
+    let revision = revision();
+    let mut records = vec![];
+    let mut rows: Vec<Vec<String>> = vec![];
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    for (day, name, input) in days
+        .into_iter()
+        .flat_map(|day| match load_inputs(day, &source) {
+            Ok(inputs) => inputs
+                .into_iter()
+                .map(|(name, input)| (day, name, Some(input)))
+                .collect_vec(),
+            Err(_) => vec![(day, None, None)],
+        })
+    {
+        let mut row = vec![day.year_str(), day_label(day, &name)];
+
+        let Some(input) = input else {
+            row.extend(["skipped (no input)".to_string(), String::new()]);
+            rows.push(row);
+            continue;
+        };
+
+        let result = runner::run_isolated(day, worker_command(day), &input, timeout);
+        records.extend(context(day, &name, &input, &revision).isolated_records(&result));
+
+        if let Err(failure) = result.parse {
+            failed += 1;
+            row.extend([format!("parse {}", failure), String::new()]);
+            rows.push(row);
+            continue;
+        }
+
+        let expected =
+            answers::read_answers(&data_dir, &day.year_str(), &day.day_str(), name.as_deref());
+
+        for (actual, expected) in [
+            (Some(result.part_1), expected.part_1),
+            (result.part_2, expected.part_2),
+        ] {
+            let actual = match actual {
+                Some(Ok(part)) => part.answer,
+                // after a timeout in part 1
+                Some(Err(Failure::NotRun)) => {
+                    row.push(Failure::NotRun.to_string());
+                    continue;
+                }
+                Some(Err(failure)) => {
+                    failed += 1;
+                    row.push(failure.to_string());
+                    continue;
+                }
+                // days without a part 2 have nothing to check
+                None => {
+                    row.push("-".to_string());
+                    continue;
+                }
+            };
+
+            row.push(match answers::check(expected.as_deref(), &actual) {
+                Verdict::Pass => {
+                    passed += 1;
+                    "ok".to_string()
+                }
+                Verdict::Fail => {
+                    failed += 1;
+                    format!("FAIL: got {}, expected {}", actual, expected.unwrap())
+                }
+                Verdict::Missing => {
+                    missing += 1;
+                    format!("missing (got {})", actual)
+                }
+            });
+        }
+
+        rows.push(row);
+    }
+
+    save_history(&records);
+
+    println!();
+    print_table(&["Year", "Day", "Part 1", "Part 2"], &rows);
+    println!();
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+
+    if failed > 0 {
+        process::exit(1);
+    }
+}
+
+fn bench(
+    days: Vec<&Day>,
+    budget: Budget,
+    save: Option<&Path>,
+    baseline: Option<&Path>,
+    threshold: f64,
+    format: Option<Format>,
+) {
+    let baseline = match baseline.map(BenchReport::load) {
+        Some(Ok(baseline)) => Some(baseline),
+        Some(Err(e)) => {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
+        None => None,
+    };
+
+    let revision = format.map(|_| revision()).unwrap_or_default();
+    let mut records = vec![];
+    let mut report = BenchReport::default();
+    let mut rows: Vec<Vec<String>> = vec![];
+    let mut regressions = 0;
+    let format_ns = |ns: u64| format!("{:.2?}", Duration::from_nanos(ns));
+
+    let source = default_source();
+
+    for day in days {
+        // named inputs are for checking answers, timing one input is enough
+        let Some((name, input)) = load_inputs(day, &source)
+            .ok()
+            .and_then(|inputs| inputs.into_iter().next())
+        else {
+            continue;
+        };
+
+        let result = bench::bench_day(day, &input, budget);
+
+        if format.is_some() {
+            records.extend(context(day, &name, &input, &revision).bench_records(&result));
+        }
+
+        let base = baseline.as_ref().and_then(|b| b.find(day.year, day.day));
+
+        for (phase, stats, base_stats) in [
+            ("parse", Some(&result.parse), base.map(|b| &b.parse)),
+            ("part 1", Some(&result.part_1), base.map(|b| &b.part_1)),
+            (
+                "part 2",
+                result.part_2.as_ref(),
+                base.and_then(|b| b.part_2.as_ref()),
+            ),
+        ] {
+            // days without a part 2
+            let Some(stats) = stats else {
+                continue;
+            };
+
+            let change = match base_stats {
+                Some(base_stats) => {
+                    let change = bench::median_change(base_stats, stats);
+
+                    if change > threshold {
+                        regressions += 1;
+                        format!("{:+.1}% REGRESSION", change)
+                    } else {
+                        format!("{:+.1}%", change)
+                    }
+                }
+                None => String::new(),
+            };
+
+            rows.push(vec![
+                day.year_str(),
+                day.day_str(),
+                phase.to_string(),
+                stats.runs.to_string(),
+                format_ns(stats.min_ns),
+                format_ns(stats.median_ns),
+                format_ns(stats.mean_ns),
+                format_ns(stats.p95_ns),
+                change,
+            ]);
+        }
+
+        report.days.push(result);
+    }
+
+    if let Some(format) = format {
+        print_records(&records, format);
+    } else {
+        println!();
+        print_table(
+            &[
+                "Year",
+                "Day",
+                "Phase",
+                "Runs",
+                "Min",
+                "Median",
+                "Mean",
+                "P95",
+                "vs baseline",
+            ],
+            &rows,
+        );
+
+        if baseline.is_some() {
+            println!();
+            println!("{} regressions above {}% (median)", regressions, threshold);
+        }
+    }
+
+    if let Some(path) = save {
+        if let Err(e) = report.save(path) {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
+
+        eprintln!("Saved results to {}", path.display());
+    }
+}
+
+fn submit(day: &Day, part: u8, source: &Source) {
+    if part == 2 && !day.has_part_2 {
+        eprintln!("Year {} day {} has no part 2", day.year, day.day_str());
+        process::exit(2);
+    }
+
+    // named inputs belong to other accounts, only the default one can be submitted
+    let input = match load_inputs(day, source)
+        .map(|inputs| inputs.into_iter().find(|(name, _)| name.is_none()))
+    {
+        Ok(Some((_, input))) => input,
+        Ok(None) => {
+            eprintln!("No input for year {} day {}", day.year, day.day_str());
+            process::exit(2);
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
+    };
+
+    let parsed = day.parse(&input);
+    let params = day.default_params();
+    let answer = if part == 1 {
+        day.part_1(&parsed, &params)
+    } else {
+        day.part_2(&parsed, &params)
+    };
+
+    println!("Submitting {} for part {}", answer, part);
+
+    let config = load_config();
+    let data_dir = config.data_dir();
+    let outcome = submit::submit(
+        &Client::from_config(&config),
+        &submit::history_path(&data_dir, &day.year_str(), &day.day_str()),
+        day.year,
+        day.day,
+        part,
+        &answer,
+    );
+
+    match outcome {
+        Ok(Outcome::Correct) => {
+            println!("Correct!");
+
+            // so `verify` keeps checking it from now on
+            let mut answers =
+                answers::read_answers(&data_dir, &day.year_str(), &day.day_str(), None);
+
+            if part == 1 {
+                answers.part_1 = Some(answer);
+            } else {
+                answers.part_2 = Some(answer);
+            }
+
+            if let Err(e) =
+                answers::write_answers(&data_dir, &day.year_str(), &day.day_str(), &answers)
+            {
+                eprintln!("{}", e);
+            }
+        }
+        Ok(Outcome::TooHigh) => println!("Wrong, too high"),
+        Ok(Outcome::TooLow) => println!("Wrong, too low"),
+        Ok(Outcome::Wrong) => println!("Wrong"),
+        Ok(Outcome::Wait(left)) => println!("Answered too recently, {} left to wait", left),
+        Ok(Outcome::WrongLevel) => println!("Wrong level, is this part already solved?"),
+        Ok(Outcome::Unknown) => println!("Could not understand the response"),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
+}
+
+fn show_history(year: u16, day: u8, threshold: f64) {
+    let entries = match history::load(&history::history_path(&load_config().data_dir())) {
+        Ok(entries) => entries,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
+    };
+    let trends = history::trends(&entries, year, day, threshold);
+
+    if trends.is_empty() {
+        println!("No runs recorded for year {} day {:02}", year, day);
+        return;
+    }
+
+    let (mut slowdowns, mut answer_changes) = (0, 0);
+
+    for trend in trends {
+        let mut rows: Vec<Vec<String>> = vec![];
+        let mut previous_answer = None;
+
+        for revision in &trend.revisions {
+            let mut notes = vec![];
+
+            if let Some(change) = revision.change {
+                notes.push(format!("{:+.1}%", change));
+            }
+
+            if revision.slower {
+                slowdowns += 1;
+                notes.push("SLOWER".to_string());
+            }
+
+            if revision.answer_changed {
+                answer_changes += 1;
+                notes.push(format!(
+                    "ANSWER CHANGED (was {})",
+                    previous_answer.unwrap_or_default()
+                ));
+            }
+
+            rows.push(vec![
+                history::format_timestamp(revision.timestamp),
+                revision.revision.clone(),
+                revision.runs.to_string(),
+                format!("{:.2?}", Duration::from_nanos(revision.median_ns)),
+                revision.answer.clone(),
+                notes.join(" "),
+            ]);
+            previous_answer = Some(revision.answer.as_str());
+        }
+
+        println!();
+        println!(
+            "Part {} on {}{}",
+            trend.part,
+            trend.machine,
+            trend
+                .input
+                .map_or(String::new(), |input| format!(", input {}", input))
+        );
+        print_table(
+            &[
+                "Date (UTC)",
+                "Revision",
+                "Runs",
+                "Median",
+                "Answer",
+                "vs previous",
+            ],
+            &rows,
+        );
+    }
+
+    println!();
+    println!(
+        "{} slowdowns above {}% (median), {} answer changes",
+        slowdowns, threshold, answer_changes
+    );
+}
+
+// `<year> <day> [command]` is how the runner was always called, so it's still accepted
+fn legacy_args(mut args: Vec<String>) -> Vec<String> {
+    if args.get(1).is_some_and(|year| year.parse::<u16>().is_ok()) {
+        let command = match args.get(3).map(String::as_str) {
+            Some(command) if !command.starts_with('-') => args.remove(3),
+            _ => "run".to_string(),
+        };
+
+        args.insert(1, command);
+    }
+
+    args
+}
+
+fn main() {
+    let cli = Cli::parse_from(legacy_args(env::args().collect()));
+
+    match cli.command {
+        Cmd::Run {
+            year,
+            days,
+            input,
+            format,
+            alloc,
+            params,
+        } => {
+            let days = select_days(Some(year), days.as_ref());
+
+            if input.is_some() && days.len() > 1 {
+                eprintln!("--input can only be used with a single day");
+                process::exit(2);
+            }
+
+            if alloc {
+                enable_alloc_counting();
+            }
+
+            run(
+                days,
+                &Source::from_arg(input.as_deref(), &load_config()),
+                format,
+                &params,
+            )
+        }
+        Cmd::All {
+            selection,
+            format,
+            alloc,
+            timeout,
+        } => {
+            if alloc {
+                enable_alloc_counting();
+            }
 
-    match command {
-        "new" => new_day(year, day),
-        "test" => test(year, day),
-        "" | "run" => run(year, day),
-        _ => eprintln!("Unrecognised command '{}'", command),
+            all(
+                select_days(selection.year, selection.days.as_ref()),
+                format,
+                timeout_from_secs(timeout),
+            )
+        }
+        Cmd::Verify { selection, timeout } => verify(
+            select_days(selection.year, selection.days.as_ref()),
+            timeout_from_secs(timeout),
+        ),
+        Cmd::Bench {
+            selection,
+            runs,
+            time,
+            save,
+            baseline,
+            threshold,
+            format,
+        } => bench(
+            select_days(selection.year, selection.days.as_ref()),
+            // a fixed number of runs wins over a time budget, which is per phase
+            runs.map_or(Budget::Time(Duration::from_secs_f64(time)), Budget::Runs),
+            save.as_deref(),
+            baseline.as_deref(),
+            threshold,
+            format,
+        ),
+        Cmd::New { day, fetch, html } => new_day(day.year, day.day, fetch, html.as_deref()),
+        Cmd::Fetch { year, days } => {
+            for &day in days.days() {
+                fetch(year, day);
+            }
+        }
+        Cmd::Puzzle { day, html } => describe(day.year, day.day, html.as_deref()),
+        Cmd::Test(day) => test(day.year, day.day),
+        Cmd::Submit { day, part, input } => submit(
+            find_day(day.year, day.day),
+            part,
+            &Source::from_arg(input.as_deref(), &load_config()),
+        ),
+        Cmd::History { day, threshold } => show_history(day.year, day.day, threshold),
+        Cmd::Worker { day, alloc } => worker(find_day(day.year, day.day), alloc),
+        Cmd::Encrypt => encrypt(),
+        Cmd::Decrypt => decrypt(),
+        Cmd::Completions { shell } => {
+            clap_complete::generate(shell, &mut Cli::command(), "aoc", &mut io::stdout())
+        }
     }
 }
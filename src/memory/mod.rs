@@ -0,0 +1,179 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt,
+    hint::black_box,
+    sync::{
+        atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering::Relaxed},
+        Mutex, PoisonError,
+    },
+};
+
+use serde::{Deserialize, Serialize};
+
+// wraps the system allocator and counts allocations while enabled - a binary opts in with
+// `#[global_allocator] static ALLOCATOR: CountingAllocator = CountingAllocator;`
+pub struct CountingAllocator;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static COUNT: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+// relative to the start of the measurement, so memory allocated before it and freed during it
+// makes this negative
+static LIVE: AtomicI64 = AtomicI64::new(0);
+static PEAK: AtomicI64 = AtomicI64::new(0);
+// the counters are shared, so only one measurement runs at a time
+static MEASURING: Mutex<()> = Mutex::new(());
+
+fn grow(size: usize) {
+    let live = LIVE.fetch_add(size as i64, Relaxed) + size as i64;
+
+    PEAK.fetch_max(live, Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if ENABLED.load(Relaxed) {
+            COUNT.fetch_add(1, Relaxed);
+            BYTES.fetch_add(layout.size() as u64, Relaxed);
+            grow(layout.size());
+        }
+
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        if ENABLED.load(Relaxed) {
+            COUNT.fetch_add(1, Relaxed);
+            BYTES.fetch_add(layout.size() as u64, Relaxed);
+            grow(layout.size());
+        }
+
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if ENABLED.load(Relaxed) {
+            LIVE.fetch_sub(layout.size() as i64, Relaxed);
+        }
+
+        System.dealloc(ptr, layout)
+    }
+
+    // counted as a new allocation of the new size, as that's what it usually costs
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        if ENABLED.load(Relaxed) {
+            COUNT.fetch_add(1, Relaxed);
+            BYTES.fetch_add(new_size as u64, Relaxed);
+            LIVE.fetch_sub(layout.size() as i64, Relaxed);
+            grow(new_size);
+        }
+
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+// what one phase allocated, across all threads
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Allocs {
+    pub count: u64,
+    pub bytes: u64,
+    // the most memory the phase held at once
+    pub peak_bytes: u64,
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{:.2} {}", value, UNITS[unit])
+}
+
+impl fmt::Display for Allocs {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} allocs, {}, peak {}",
+            self.count,
+            format_bytes(self.bytes),
+            format_bytes(self.peak_bytes)
+        )
+    }
+}
+
+// starts counting, false if CountingAllocator isn't the global allocator so there'd be nothing
+// to count
+pub fn enable() -> bool {
+    ENABLED.store(true, Relaxed);
+
+    let before = COUNT.load(Relaxed);
+    drop(black_box(Box::new(0u64)));
+
+    if COUNT.load(Relaxed) == before {
+        ENABLED.store(false, Relaxed);
+    }
+
+    is_enabled()
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Relaxed)
+}
+
+// None unless counting is enabled - measurements don't nest, concurrent ones wait for each
+// other, and anything other threads allocate meanwhile is counted too
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Allocs>) {
+    if !is_enabled() {
+        return (f(), None);
+    }
+
+    // a phase that panicked leaves the lock poisoned, the counters are reset anyway
+    let _measuring = MEASURING.lock().unwrap_or_else(PoisonError::into_inner);
+
+    COUNT.store(0, Relaxed);
+    BYTES.store(0, Relaxed);
+    LIVE.store(0, Relaxed);
+    PEAK.store(0, Relaxed);
+
+    let result = f();
+
+    let allocs = Allocs {
+        count: COUNT.load(Relaxed),
+        bytes: BYTES.load(Relaxed),
+        peak_bytes: PEAK.load(Relaxed).max(0) as u64,
+    };
+
+    (result, Some(allocs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.50 KiB");
+        assert_eq!(format_bytes(5 * 1024 * 1024), "5.00 MiB");
+        assert_eq!(
+            Allocs {
+                count: 3,
+                bytes: 2048,
+                peak_bytes: 100
+            }
+            .to_string(),
+            "3 allocs, 2.00 KiB, peak 100 B"
+        );
+    }
+}
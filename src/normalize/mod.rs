@@ -0,0 +1,135 @@
+use std::fmt;
+
+const BOM: char = '\u{feff}';
+
+// what normalize had to fix, so a badly saved input doesn't go unnoticed
+#[derive(Debug, Default, PartialEq)]
+pub struct Changes {
+    pub bom: bool,
+    pub crlf_lines: usize,
+    pub trailing_whitespace_lines: usize,
+}
+
+impl Changes {
+    pub fn is_empty(&self) -> bool {
+        *self == Changes::default()
+    }
+}
+
+impl fmt::Display for Changes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut changes = vec![];
+
+        if self.bom {
+            changes.push("removed a byte order mark".to_string());
+        }
+
+        if self.crlf_lines > 0 {
+            changes.push(format!("converted {} CRLF line endings", self.crlf_lines));
+        }
+
+        if self.trailing_whitespace_lines > 0 {
+            changes.push(format!(
+                "trimmed trailing whitespace on {} lines",
+                self.trailing_whitespace_lines
+            ));
+        }
+
+        write!(f, "{}", changes.join(", "))
+    }
+}
+
+// strips a BOM, turns CRLF into LF and trims whitespace at the end of every line, plus the
+// trailing newlines every input ends with (those don't count as a change)
+pub fn normalize(input: &str) -> (String, Changes) {
+    let mut changes = Changes::default();
+
+    let input = match input.strip_prefix(BOM) {
+        Some(input) => {
+            changes.bom = true;
+            input
+        }
+        None => input,
+    };
+
+    let lines = input
+        .split('\n')
+        .map(|line| {
+            let line = match line.strip_suffix('\r') {
+                Some(line) => {
+                    changes.crlf_lines += 1;
+                    line
+                }
+                None => line,
+            };
+            let trimmed = line.trim_end_matches([' ', '\t']);
+
+            if trimmed.len() != line.len() {
+                changes.trailing_whitespace_lines += 1;
+            }
+
+            trimmed
+        })
+        .collect::<Vec<&str>>();
+
+    (lines.join("\n").trim_end_matches('\n').to_string(), changes)
+}
+
+// inputs are printable ASCII, anything else is usually a copy & paste accident
+pub fn check_characters(input: &str) -> Result<(), String> {
+    for (row, line) in input.lines().enumerate() {
+        if let Some((col, ch)) = line
+            .chars()
+            .enumerate()
+            .find(|&(_, ch)| !(' '..='~').contains(&ch))
+        {
+            return Err(format!(
+                "Unexpected character {:?} at line {}, column {}",
+                ch,
+                row + 1,
+                col + 1
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(
+            normalize("1 2\n3 4\n\n"),
+            ("1 2\n3 4".to_string(), Changes::default())
+        );
+
+        let (input, changes) = normalize("\u{feff}1 2 \r\n  3 4\r\n\r\n");
+
+        assert_eq!(input, "1 2\n  3 4");
+        assert_eq!(
+            changes,
+            Changes {
+                bom: true,
+                crlf_lines: 3,
+                trailing_whitespace_lines: 1,
+            }
+        );
+        assert_eq!(
+            changes.to_string(),
+            "removed a byte order mark, converted 3 CRLF line endings, trimmed trailing whitespace on 1 lines"
+        );
+    }
+
+    #[test]
+    fn test_check_characters() {
+        assert!(check_characters("#.#\n..~ @").is_ok());
+        assert_eq!(
+            check_characters("ok\nx\u{a0}y"),
+            Err("Unexpected character '\\u{a0}' at line 2, column 2".to_string())
+        );
+        assert!(check_characters("a\tb").is_err());
+    }
+}
@@ -0,0 +1,312 @@
+use std::{fs, path::PathBuf};
+
+use itertools::Itertools;
+
+#[derive(Debug, PartialEq)]
+enum Token<'a> {
+    Open(&'a str, &'a str),
+    Close(&'a str),
+    Text(&'a str),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PartExample {
+    // index into Puzzle::examples
+    pub example: usize,
+    pub answer: Option<String>,
+}
+
+// what `new` needs from a puzzle page: a readable description and the example tests
+#[derive(Debug, Default, PartialEq)]
+pub struct Puzzle {
+    pub markdown: String,
+    pub examples: Vec<String>,
+    // one per part that's visible on the page, part 2 only shows up once part 1 is solved
+    pub parts: Vec<PartExample>,
+}
+
+pub fn description_path(year: &str, day: &str) -> PathBuf {
+    crate::solution_path(year, day).with_extension("md")
+}
+
+pub fn write_description(year: &str, day: &str, puzzle: &Puzzle) -> Result<PathBuf, String> {
+    let path = description_path(year, day);
+
+    fs::write(&path, &puzzle.markdown)
+        .map_err(|e| format!("Could not write {}: {}", path.display(), e))?;
+
+    Ok(path)
+}
+
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+        }
+
+        let Some(end) = rest[start..].find('>').map(|end| start + end) else {
+            tokens.push(Token::Text(&rest[start..]));
+            break;
+        };
+
+        let tag = &rest[start + 1..end];
+
+        if let Some(name) = tag.strip_prefix('/') {
+            tokens.push(Token::Close(name.trim()));
+        } else if !tag.starts_with('!') {
+            let (name, attrs) = tag.split_once(' ').unwrap_or((tag, ""));
+            tokens.push(Token::Open(name, attrs.trim_end_matches('/')));
+        }
+
+        rest = &rest[end + 1..];
+    }
+
+    tokens
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+fn attr<'a>(attrs: &'a str, name: &str) -> Option<&'a str> {
+    attrs
+        .split_once(&format!("{}=\"", name))
+        .and_then(|(_, rest)| rest.split_once('"'))
+        .map(|(value, _)| value)
+}
+
+// the tokens of every `<article>`, which is where each part's description lives
+fn articles<'a>(tokens: &'a [Token<'a>]) -> Vec<&'a [Token<'a>]> {
+    let mut articles = vec![];
+    let mut start = None;
+
+    for (i, token) in tokens.iter().enumerate() {
+        match token {
+            Token::Open("article", _) => start = Some(i + 1),
+            Token::Close("article") => {
+                if let Some(start) = start.take() {
+                    articles.push(&tokens[start..i]);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    articles
+}
+
+fn to_markdown(article: &[Token]) -> String {
+    let mut markdown = String::new();
+    let mut in_pre = false;
+    let mut code_depth = 0;
+    let mut links = vec![];
+
+    for token in article {
+        match *token {
+            Token::Open("pre", _) => {
+                in_pre = true;
+                markdown += "```\n";
+            }
+            Token::Close("pre") => {
+                in_pre = false;
+
+                if !markdown.ends_with('\n') {
+                    markdown += "\n";
+                }
+
+                markdown += "```\n\n";
+            }
+            Token::Text(text) if in_pre => markdown += &decode_entities(text),
+            // highlights inside example blocks have no Markdown equivalent
+            _ if in_pre => {}
+            Token::Open("h2", _) => markdown += "## ",
+            Token::Close("h2" | "p") => markdown += "\n\n",
+            Token::Close("ul") => markdown += "\n",
+            Token::Open("li", _) => markdown += "- ",
+            Token::Close("li") => markdown += "\n",
+            Token::Open("code", _) => {
+                code_depth += 1;
+                markdown += "`";
+            }
+            Token::Close("code") => {
+                code_depth -= 1;
+                markdown += "`";
+            }
+            Token::Open("em", _) | Token::Close("em") if code_depth == 0 => markdown += "*",
+            Token::Open("a", attrs) => {
+                links.push(attr(attrs, "href").unwrap_or_default());
+                markdown += "[";
+            }
+            Token::Close("a") => markdown += &format!("]({})", links.pop().unwrap_or_default()),
+            Token::Text(text) => {
+                let text = decode_entities(text);
+                let collapsed = text.split_whitespace().join(" ");
+
+                // keep the spaces around inline tags, but don't start lines with one
+                if text.starts_with(char::is_whitespace) && !markdown.ends_with([' ', '\n']) {
+                    markdown += " ";
+                }
+
+                markdown += &collapsed;
+
+                if text.ends_with(char::is_whitespace) && !collapsed.is_empty() {
+                    markdown += " ";
+                }
+            }
+            _ => {}
+        }
+    }
+
+    markdown
+        .lines()
+        .map(str::trim_end)
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+// `<code><em>142</em></code>` (or the other way around) is how the site highlights results
+fn highlighted_code(tokens: &[Token]) -> Option<String> {
+    match tokens {
+        [Token::Open("code", _), Token::Open("em", _), Token::Text(text), Token::Close("em"), Token::Close("code")]
+        | [Token::Open("em", _), Token::Open("code", _), Token::Text(text), Token::Close("code"), Token::Close("em")] => {
+            Some(decode_entities(text))
+        }
+        _ => None,
+    }
+}
+
+pub fn parse_puzzle(html: &str) -> Puzzle {
+    let tokens = tokenize(html);
+    let mut puzzle = Puzzle::default();
+    let mut sections = vec![];
+
+    for article in articles(&tokens) {
+        sections.push(to_markdown(article));
+
+        // (token index, example index) of the article's example blocks
+        let mut blocks = vec![];
+        let mut answer = None;
+
+        for i in 0..article.len() {
+            if let [Token::Open("pre", _), Token::Open("code", _), Token::Text(text), Token::Close("code"), Token::Close("pre"), ..] =
+                article[i..]
+            {
+                blocks.push((i, puzzle.examples.len()));
+                puzzle
+                    .examples
+                    .push(decode_entities(text).trim_end_matches('\n').to_string());
+            }
+
+            if let Some(code) = article.get(i..i + 5).and_then(highlighted_code) {
+                answer = Some((i, code));
+            }
+        }
+
+        // the answer is the last highlight, and belongs to the closest example block above it;
+        // part 2 often reuses part 1's example without repeating it
+        let example = match &answer {
+            Some((answer_at, _)) => blocks
+                .iter()
+                .rev()
+                .find(|(block_at, _)| block_at < answer_at)
+                .or(blocks.first())
+                .map(|&(_, example)| example),
+            None => blocks.first().map(|&(_, example)| example),
+        }
+        .or(puzzle.examples.len().checked_sub(1));
+
+        if let Some(example) = example {
+            puzzle.parts.push(PartExample {
+                example,
+                answer: answer.map(|(_, answer)| answer),
+            });
+        }
+    }
+
+    puzzle.markdown = sections.join("\n\n") + "\n";
+
+    puzzle
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2>
+<p>For example, see <a href="/2023/about">this</a>:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+</code></pre>
+<p>In this example, the values of these lines are <code>12</code> and <code>38</code>. Adding these together produces <code><em>50</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>54</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<ul><li>a &lt; b &amp;&amp; c</li></ul>
+<p>In the above example, the answer is <em><code>77</code></em>.</p>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn test_parse_puzzle() {
+        let puzzle = parse_puzzle(PAGE);
+
+        assert_eq!(puzzle.examples, vec!["1abc2\npqr3stu8vwx"]);
+        assert_eq!(
+            puzzle.parts,
+            vec![
+                PartExample {
+                    example: 0,
+                    answer: Some("50".to_string())
+                },
+                PartExample {
+                    example: 0,
+                    answer: Some("77".to_string())
+                },
+            ]
+        );
+        assert_eq!(
+            puzzle.markdown,
+            r#"## --- Day 1: Trebuchet?! ---
+
+For example, see [this](/2023/about):
+
+```
+1abc2
+pqr3stu8vwx
+```
+
+In this example, the values of these lines are `12` and `38`. Adding these together produces `50`.
+
+## --- Part Two ---
+
+- a < b && c
+
+In the above example, the answer is *`77`*.
+"#
+        );
+    }
+
+    #[test]
+    fn test_parse_puzzle_without_examples() {
+        let puzzle = parse_puzzle("<article><p>No examples today</p></article>");
+
+        assert_eq!(puzzle.markdown, "No examples today\n");
+        assert!(puzzle.examples.is_empty());
+        assert!(puzzle.parts.is_empty());
+    }
+}
@@ -0,0 +1,324 @@
+use std::{path::Path, process::Command, time::Duration};
+
+use clap::ValueEnum;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bench::DayBench,
+    runner::{DayRun, IsolatedRun, ParseRun, PartRun},
+};
+
+// machine-readable output for run/all/bench, the default is the human-readable tables
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum Format {
+    Json,
+    Csv,
+    Markdown,
+}
+
+// one line of output - a part of one day on one input
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    // None for the default input, see input::find_inputs
+    pub input: Option<String>,
+    // "parse", "1" or "2"
+    pub part: String,
+    // empty for parse, and for bench, which doesn't check answers
+    pub answer: String,
+    // the median for bench
+    pub duration_ns: u64,
+    pub input_hash: String,
+    pub revision: String,
+}
+
+// what every record of one input has in common
+pub struct Context<'a> {
+    pub year: u16,
+    pub day: u8,
+    pub input_name: Option<&'a str>,
+    pub input: &'a str,
+    pub revision: &'a str,
+}
+
+impl Context<'_> {
+    fn record(&self, part: &str, answer: &str, duration_ns: u64) -> Record {
+        Record {
+            year: self.year,
+            day: self.day,
+            input: self.input_name.map(str::to_string),
+            part: part.to_string(),
+            answer: answer.to_string(),
+            duration_ns,
+            input_hash: format!("{:x}", md5::compute(self.input)),
+            revision: self.revision.to_string(),
+        }
+    }
+
+    // a record for every phase that's there
+    fn phase_records(
+        &self,
+        parse: Option<&ParseRun>,
+        part_1: Option<&PartRun>,
+        part_2: Option<&PartRun>,
+    ) -> Vec<Record> {
+        let parse = parse.map(|parse| self.record("parse", "", parse.time.as_nanos() as u64));
+        let parts = [("1", part_1), ("2", part_2)]
+            .into_iter()
+            .filter_map(|(part, run)| {
+                run.map(|run| self.record(part, &run.answer, run.time.as_nanos() as u64))
+            });
+
+        parse.into_iter().chain(parts).collect()
+    }
+
+    pub fn run_records(&self, result: &DayRun) -> Vec<Record> {
+        self.phase_records(
+            Some(&result.parse),
+            Some(&result.part_1),
+            result.part_2.as_ref(),
+        )
+    }
+
+    // failed phases are left out
+    pub fn isolated_records(&self, result: &IsolatedRun) -> Vec<Record> {
+        self.phase_records(
+            result.parse.as_ref().ok(),
+            result.part_1.as_ref().ok(),
+            result
+                .part_2
+                .as_ref()
+                .and_then(|part_2| part_2.as_ref().ok()),
+        )
+    }
+
+    pub fn bench_records(&self, result: &DayBench) -> Vec<Record> {
+        let mut records = vec![
+            self.record("parse", "", result.parse.median_ns),
+            self.record("1", "", result.part_1.median_ns),
+        ];
+
+        if let Some(part_2) = &result.part_2 {
+            records.push(self.record("2", "", part_2.median_ns));
+        }
+
+        records
+    }
+}
+
+// short hash of the checked out commit, with `-dirty` if there are uncommitted changes
+pub fn git_revision(repo: &Path) -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(repo)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    let Some(revision) = git(&["rev-parse", "--short", "HEAD"]) else {
+        return "unknown".to_string();
+    };
+
+    match git(&["status", "--porcelain", "--untracked-files=no"]) {
+        Some(status) if !status.is_empty() => format!("{}-dirty", revision),
+        _ => revision,
+    }
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+pub fn format_records(records: &[Record], format: Format) -> String {
+    match format {
+        Format::Json => serde_json::to_string_pretty(records).unwrap() + "\n",
+        Format::Csv => {
+            let mut csv =
+                "year,day,input,part,answer,duration_ns,input_hash,revision\n".to_string();
+
+            for r in records {
+                csv += &[
+                    r.year.to_string(),
+                    r.day.to_string(),
+                    r.input.clone().unwrap_or_default(),
+                    r.part.clone(),
+                    r.answer.clone(),
+                    r.duration_ns.to_string(),
+                    r.input_hash.clone(),
+                    r.revision.clone(),
+                ]
+                .iter()
+                .map(|field| csv_field(field))
+                .join(",");
+                csv += "\n";
+            }
+
+            csv
+        }
+        Format::Markdown => {
+            let mut markdown =
+                "| Year | Day | Input | Part | Answer | Time | Input hash | Revision |\n\
+                 |------|-----|-------|------|--------|------|------------|----------|\n"
+                    .to_string();
+
+            for r in records {
+                markdown += &format!(
+                    "| {} | {:02} | {} | {} | {} | {:.2?} | {} | {} |\n",
+                    r.year,
+                    r.day,
+                    r.input.as_deref().unwrap_or("-"),
+                    r.part,
+                    r.answer.replace('|', "\\|"),
+                    Duration::from_nanos(r.duration_ns),
+                    r.input_hash,
+                    r.revision
+                );
+            }
+
+            markdown
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bench::{DayBench, Stats},
+        runner::Failure,
+    };
+
+    fn records() -> Vec<Record> {
+        let context = Context {
+            year: 2023,
+            day: 5,
+            input_name: None,
+            input: "1 2 3",
+            revision: "abc1234",
+        };
+
+        context.run_records(&DayRun {
+            parse: ParseRun {
+                time: Duration::from_micros(3),
+                allocs: None,
+            },
+            part_1: PartRun {
+                answer: "35".to_string(),
+                time: Duration::from_micros(12),
+                allocs: None,
+            },
+            part_2: Some(PartRun {
+                answer: "a,b".to_string(),
+                time: Duration::from_nanos(1500),
+                allocs: None,
+            }),
+        })
+    }
+
+    #[test]
+    fn test_format_csv() {
+        assert_eq!(
+            format_records(&records(), Format::Csv),
+            "year,day,input,part,answer,duration_ns,input_hash,revision
+2023,5,,parse,,3000,5ba48b6e5a7c4d4930fda256f411e55b,abc1234
+2023,5,,1,35,12000,5ba48b6e5a7c4d4930fda256f411e55b,abc1234
+2023,5,,2,\"a,b\",1500,5ba48b6e5a7c4d4930fda256f411e55b,abc1234
+"
+        );
+    }
+
+    #[test]
+    fn test_format_markdown() {
+        assert_eq!(
+            format_records(&records()[1..2], Format::Markdown),
+            "| Year | Day | Input | Part | Answer | Time | Input hash | Revision |
+|------|-----|-------|------|--------|------|------------|----------|
+| 2023 | 05 | - | 1 | 35 | 12.00µs | 5ba48b6e5a7c4d4930fda256f411e55b | abc1234 |
+"
+        );
+    }
+
+    #[test]
+    fn test_format_json() {
+        let json: serde_json::Value =
+            serde_json::from_str(&format_records(&records(), Format::Json)).unwrap();
+
+        assert_eq!(json[0]["part"], "parse");
+        assert_eq!(json[2]["answer"], "a,b");
+        assert_eq!(json[2]["duration_ns"], 1500);
+        assert_eq!(json[2]["input"], serde_json::Value::Null);
+    }
+
+    #[test]
+    fn test_bench_records() {
+        let context = Context {
+            year: 2023,
+            day: 25,
+            input_name: None,
+            input: "1 2 3",
+            revision: "abc1234",
+        };
+        let stats = |median_ns| Stats {
+            runs: 1,
+            min_ns: median_ns,
+            median_ns,
+            mean_ns: median_ns,
+            p95_ns: median_ns,
+        };
+        let records = context.bench_records(&DayBench {
+            year: 2023,
+            day: 25,
+            parse: stats(10),
+            part_1: stats(20),
+            part_2: None,
+        });
+
+        assert_eq!(
+            records
+                .iter()
+                .map(|r| (r.part.as_str(), r.duration_ns))
+                .collect_vec(),
+            vec![("parse", 10), ("1", 20)]
+        );
+    }
+
+    #[test]
+    fn test_isolated_records() {
+        let context = Context {
+            year: 2023,
+            day: 5,
+            input_name: Some("alice"),
+            input: "1 2 3",
+            revision: "abc1234",
+        };
+        let records = context.isolated_records(&IsolatedRun {
+            parse: Ok(ParseRun {
+                time: Duration::from_micros(3),
+                allocs: None,
+            }),
+            part_1: Ok(PartRun {
+                answer: "35".to_string(),
+                time: Duration::from_micros(12),
+                allocs: None,
+            }),
+            part_2: Some(Err(Failure::Timeout(Duration::from_secs(1)))),
+        });
+
+        assert_eq!(
+            records
+                .iter()
+                .map(|r| (r.part.as_str(), r.answer.as_str(), r.input.as_deref()))
+                .collect_vec(),
+            vec![("parse", "", Some("alice")), ("1", "35", Some("alice"))]
+        );
+    }
+}
@@ -0,0 +1,493 @@
+use std::{
+    cell::RefCell,
+    fmt,
+    io::{BufRead, BufReader, Write},
+    panic::{self, AssertUnwindSafe},
+    process::{Command, Stdio},
+    sync::{
+        mpsc::{self, RecvTimeoutError},
+        Once,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    input::{self, LoadedInput, Source},
+    memory::{self, Allocs},
+    solution::{Day, Params},
+};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct PartRun {
+    pub answer: String,
+    pub time: Duration,
+    // only when allocation counting is enabled, see memory::enable
+    pub allocs: Option<Allocs>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct ParseRun {
+    pub time: Duration,
+    pub allocs: Option<Allocs>,
+}
+
+pub struct DayRun {
+    pub parse: ParseRun,
+    pub part_1: PartRun,
+    // None for days without a part 2, see Solution::HAS_PART_2
+    pub part_2: Option<PartRun>,
+}
+
+impl DayRun {
+    pub fn total_time(&self) -> Duration {
+        self.parse.time
+            + self.part_1.time
+            + self
+                .part_2
+                .as_ref()
+                .map_or(Duration::ZERO, |part_2| part_2.time)
+    }
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration, Option<Allocs>) {
+    let start = Instant::now();
+    let (result, allocs) = memory::measure(f);
+
+    (result, start.elapsed(), allocs)
+}
+
+fn timed_part(f: impl FnOnce() -> String) -> PartRun {
+    let (answer, time, allocs) = timed(f);
+
+    PartRun {
+        answer,
+        time,
+        allocs,
+    }
+}
+
+pub fn run(day: &Day, input: &str, params: &Params) -> DayRun {
+    let (parsed, time, allocs) = timed(|| day.parse(input));
+
+    DayRun {
+        parse: ParseRun { time, allocs },
+        part_1: timed_part(|| day.part_1(&parsed, params)),
+        part_2: day
+            .has_part_2
+            .then(|| timed_part(|| day.part_2(&parsed, params))),
+    }
+}
+
+// the inputs of a day that have something in them - `new` creates empty input files, so those are
+// expected while a day is in progress, and a day without any is skipped
+pub fn load_inputs(day: &Day, source: &Source, strict: bool) -> Result<Vec<LoadedInput>, String> {
+    let inputs = input::read_inputs(source, day.year, day.day, strict)?
+        .into_iter()
+        .filter(|input| !input.text.trim().is_empty())
+        .collect::<Vec<LoadedInput>>();
+
+    if inputs.is_empty() {
+        return Err(format!(
+            "The input for year {} day {} is empty",
+            day.year,
+            day.day_str()
+        ));
+    }
+
+    Ok(inputs)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub enum Failure {
+    Timeout(Duration),
+    Panic { message: String, location: String },
+    // the worker died without saying why, e.g. of a stack overflow
+    Crash(String),
+    // an earlier phase failed, or took all the time
+    NotRun,
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Failure::Timeout(timeout) => write!(f, "TIMEOUT after {:?}", timeout),
+            Failure::Panic { message, location } => write!(f, "PANIC at {}: {}", location, message),
+            Failure::Crash(status) => write!(f, "CRASH ({})", status),
+            Failure::NotRun => write!(f, "not run"),
+        }
+    }
+}
+
+// what an isolated run got to, every phase succeeds or fails on its own - a part 2 that panics
+// doesn't cost part 1 its answer
+#[derive(Debug, PartialEq)]
+pub struct IsolatedRun {
+    pub parse: Result<ParseRun, Failure>,
+    pub part_1: Result<PartRun, Failure>,
+    // None for days without a part 2, see Solution::HAS_PART_2
+    pub part_2: Option<Result<PartRun, Failure>>,
+}
+
+impl IsolatedRun {
+    fn not_run(has_part_2: bool) -> IsolatedRun {
+        IsolatedRun {
+            parse: Err(Failure::NotRun),
+            part_1: Err(Failure::NotRun),
+            part_2: has_part_2.then_some(Err(Failure::NotRun)),
+        }
+    }
+
+    // of the phases that finished
+    pub fn total_time(&self) -> Duration {
+        let parse = self
+            .parse
+            .as_ref()
+            .map_or(Duration::ZERO, |parse| parse.time);
+        let parts = [Some(&self.part_1), self.part_2.as_ref()]
+            .into_iter()
+            .flatten()
+            .filter_map(|part| part.as_ref().ok())
+            .map(|part| part.time);
+
+        parse + parts.sum::<Duration>()
+    }
+
+    // the phase the worker was on when it timed out or died
+    fn fail_current(&mut self, failure: Failure) {
+        if self.parse == Err(Failure::NotRun) {
+            self.parse = Err(failure);
+        } else if self.parse.is_err() {
+            // nothing ran after the parse failed
+        } else if self.part_1 == Err(Failure::NotRun) {
+            self.part_1 = Err(failure);
+        } else if self.part_2 == Some(Err(Failure::NotRun)) {
+            self.part_2 = Some(Err(failure));
+        }
+    }
+}
+
+thread_local! {
+    // set while a phase runs in the worker, so its panic is captured instead of printed
+    static CAPTURED_PANIC: RefCell<Option<Option<Failure>>> = const { RefCell::new(None) };
+}
+
+fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+
+    INSTALL.call_once(|| {
+        let default_hook = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| {
+            let captured = CAPTURED_PANIC.with_borrow_mut(|captured| {
+                let slot = captured.as_mut()?;
+                let payload = info.payload();
+                let message = payload
+                    .downcast_ref::<&str>()
+                    .map(|message| message.to_string())
+                    .or_else(|| payload.downcast_ref::<String>().cloned())
+                    .unwrap_or_else(|| "Box<dyn Any>".to_string());
+
+                *slot = Some(Failure::Panic {
+                    message,
+                    location: info.location().map_or("unknown".to_string(), |l| {
+                        format!("{}:{}", l.file(), l.line())
+                    }),
+                });
+
+                Some(())
+            });
+
+            if captured.is_none() {
+                default_hook(info);
+            }
+        }));
+    });
+}
+
+fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, Failure> {
+    CAPTURED_PANIC.set(Some(None));
+
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    let captured = CAPTURED_PANIC.take().flatten();
+
+    result.map_err(|_| {
+        captured.unwrap_or(Failure::Panic {
+            message: "unknown".to_string(),
+            location: "unknown".to_string(),
+        })
+    })
+}
+
+// a finished phase, as the worker reports it on a line of its own
+const REPORT_PREFIX: &str = "aoc-worker-report ";
+
+#[derive(Serialize, Deserialize)]
+enum Report {
+    Parse(Result<ParseRun, Failure>),
+    Part1(Result<PartRun, Failure>),
+    Part2(Result<PartRun, Failure>),
+}
+
+fn send_report(out: &mut impl Write, report: &Report) {
+    // the parent is gone if this fails, and there's no one left to tell
+    let _ = writeln!(
+        out,
+        "{}{}",
+        REPORT_PREFIX,
+        serde_json::to_string(report).unwrap()
+    );
+    let _ = out.flush();
+}
+
+// the worker's side of run_isolated: runs the phases one after the other with the default params,
+// and reports each as soon as it's done - part 2 runs even if part 1 panicked
+pub fn run_worker(day: &Day, input: &str, out: &mut impl Write) {
+    install_panic_hook();
+
+    let params = day.default_params();
+    let parsed = match catch_panic(|| timed(|| day.parse(input))) {
+        Ok((parsed, time, allocs)) => {
+            send_report(out, &Report::Parse(Ok(ParseRun { time, allocs })));
+            parsed
+        }
+        Err(failure) => return send_report(out, &Report::Parse(Err(failure))),
+    };
+
+    send_report(
+        out,
+        &Report::Part1(catch_panic(|| timed_part(|| day.part_1(&parsed, &params)))),
+    );
+
+    if day.has_part_2 {
+        send_report(
+            out,
+            &Report::Part2(catch_panic(|| timed_part(|| day.part_2(&parsed, &params)))),
+        );
+    }
+}
+
+// runs the day in `worker`, a process that calls run_worker with the input from its stdin, so a
+// panic, a crash or an endless loop only costs this day - a worker that runs out of time is killed
+pub fn run_isolated(
+    day: &Day,
+    mut worker: Command,
+    input: &str,
+    timeout: Option<Duration>,
+) -> IsolatedRun {
+    let mut run = IsolatedRun::not_run(day.has_part_2);
+    let mut child = match worker.stdin(Stdio::piped()).stdout(Stdio::piped()).spawn() {
+        Ok(child) => child,
+        Err(e) => {
+            run.fail_current(Failure::Crash(format!("could not start the worker: {}", e)));
+            return run;
+        }
+    };
+
+    // a worker that exits without reading it is reported below
+    let mut stdin = child.stdin.take().unwrap();
+    let _ = stdin.write_all(input.as_bytes());
+    drop(stdin);
+
+    let stdout = child.stdout.take().unwrap();
+    let (sender, receiver) = mpsc::channel();
+
+    // solutions can print too, only the report lines are read
+    thread::spawn(move || {
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            let Some((_, json)) = line.split_once(REPORT_PREFIX) else {
+                continue;
+            };
+
+            if let Ok(report) = serde_json::from_str::<Report>(json) {
+                if sender.send(report).is_err() {
+                    return;
+                }
+            }
+        }
+    });
+
+    let deadline = timeout.map(|timeout| Instant::now() + timeout);
+
+    loop {
+        let received = match deadline {
+            Some(deadline) => {
+                receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+            }
+            None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+
+        match received {
+            Ok(Report::Parse(parse)) => run.parse = parse,
+            Ok(Report::Part1(part_1)) => run.part_1 = part_1,
+            Ok(Report::Part2(part_2)) => run.part_2 = Some(part_2),
+            Err(RecvTimeoutError::Timeout) => {
+                let _ = child.kill();
+                let _ = child.wait();
+                run.fail_current(Failure::Timeout(timeout.unwrap()));
+
+                return run;
+            }
+            // the worker's stdout is closed, so it's done
+            Err(RecvTimeoutError::Disconnected) => {
+                match child.wait() {
+                    Ok(status) if status.success() => {}
+                    Ok(status) => run.fail_current(Failure::Crash(status.to_string())),
+                    Err(e) => run.fail_current(Failure::Crash(e.to_string())),
+                }
+
+                return run;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env, fs,
+        io::{self, Read},
+    };
+
+    use super::*;
+    use crate::solution::Solution;
+
+    struct Panics;
+
+    impl Solution for Panics {
+        type Input = String;
+
+        fn parse(input: &str) -> String {
+            input.to_string()
+        }
+
+        fn part_1(input: &String, _: &Params) -> String {
+            input.clone()
+        }
+
+        fn part_2(input: &String, _: &Params) -> String {
+            panic!("no part 2 for {}", input);
+        }
+    }
+
+    struct Hangs;
+
+    impl Solution for Hangs {
+        type Input = ();
+
+        fn parse(_: &str) {}
+
+        fn part_1(_: &(), _: &Params) -> String {
+            loop {
+                thread::sleep(Duration::from_secs(1));
+            }
+        }
+
+        fn part_2(_: &(), _: &Params) -> String {
+            String::new()
+        }
+    }
+
+    static PANICS: Day = Day::new::<Panics>(2023, 1);
+    static HANGS: Day = Day::new::<Hangs>(2023, 2);
+
+    // the worker for test_run_isolated, which runs this test binary with AOC_TEST_WORKER set
+    #[test]
+    fn worker_process() {
+        let day = match env::var("AOC_TEST_WORKER").as_deref() {
+            Ok("panics") => &PANICS,
+            Ok("hangs") => &HANGS,
+            _ => return,
+        };
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input).unwrap();
+
+        run_worker(day, &input, &mut io::stdout());
+    }
+
+    fn worker(name: &str) -> Command {
+        let mut command = Command::new(env::current_exe().unwrap());
+        command
+            .args(["runner::tests::worker_process", "--exact", "--nocapture"])
+            .env("AOC_TEST_WORKER", name);
+
+        command
+    }
+
+    #[test]
+    fn test_run_worker() {
+        let mut out = vec![];
+        run_worker(&PANICS, "abc", &mut out);
+
+        let reports = String::from_utf8(out)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line.strip_prefix(REPORT_PREFIX).unwrap()).unwrap())
+            .collect::<Vec<Report>>();
+
+        assert_eq!(reports.len(), 3);
+        assert!(matches!(reports[0], Report::Parse(Ok(_))));
+        assert!(matches!(&reports[1], Report::Part1(Ok(part_1)) if part_1.answer == "abc"));
+        assert!(matches!(
+            &reports[2],
+            Report::Part2(Err(Failure::Panic { message, .. })) if message == "no part 2 for abc"
+        ));
+    }
+
+    #[test]
+    fn test_run_isolated() {
+        let run = run_isolated(&PANICS, worker("panics"), "abc", None);
+
+        assert!(run.parse.is_ok());
+        assert_eq!(
+            run.part_1.map(|part_1| part_1.answer),
+            Ok("abc".to_string())
+        );
+        assert!(matches!(
+            run.part_2,
+            Some(Err(Failure::Panic { ref message, ref location }))
+                if message == "no part 2 for abc" && location.starts_with("src/runner/mod.rs:")
+        ));
+
+        let timeout = Duration::from_millis(500);
+        let run = run_isolated(&HANGS, worker("hangs"), "", Some(timeout));
+
+        assert!(run.parse.is_ok());
+        assert_eq!(run.part_1, Err(Failure::Timeout(timeout)));
+        assert_eq!(run.part_2, Some(Err(Failure::NotRun)));
+    }
+
+    #[test]
+    fn test_load_inputs() {
+        let dir = env::temp_dir().join(format!("aoc_rust_runner_{}", std::process::id()));
+        let named = dir.join("year2023_day01");
+        fs::create_dir_all(&named).unwrap();
+
+        let source = Source::Dir(dir.clone());
+
+        // no input at all, `all` skips the day
+        assert!(load_inputs(&PANICS, &source, false).is_err_and(|e| e.contains("No input")));
+
+        fs::write(dir.join("year2023_day01.txt"), "\n").unwrap();
+
+        assert_eq!(
+            load_inputs(&PANICS, &source, false).err(),
+            Some("The input for year 2023 day 01 is empty".to_string())
+        );
+
+        // an empty default input doesn't stop the named ones from running
+        fs::write(named.join("alice.txt"), "alice's").unwrap();
+
+        assert_eq!(
+            load_inputs(&PANICS, &source, false).map(|inputs| inputs
+                .into_iter()
+                .map(|input| (input.name, input.text))
+                .collect::<Vec<_>>()),
+            Ok(vec![(Some("alice".to_string()), "alice's".to_string())])
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
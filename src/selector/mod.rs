@@ -0,0 +1,101 @@
+use std::{ops::RangeInclusive, str::FromStr};
+
+use itertools::Itertools;
+
+const DAYS: RangeInclusive<u8> = 1..=25;
+
+// the days of a year to act on, as written on the command line: `5`, `1..=10`, `1..10`, `3,7,12`
+// or any mix of those like `1..=3,7`
+#[derive(Clone, Debug, PartialEq)]
+pub struct DaySelector(Vec<u8>);
+
+fn parse_number(day: &str) -> Result<u8, String> {
+    day.trim().parse::<u8>().map_err(|_| {
+        format!(
+            "'{}' is not a day, expected e.g. 5, 1..=10, 1..11 or 3,7,12",
+            day.trim()
+        )
+    })
+}
+
+fn parse_day(day: &str) -> Result<u8, String> {
+    Some(parse_number(day)?)
+        .filter(|day| DAYS.contains(day))
+        .ok_or(format!("'{}' is not a day between 1 and 25", day.trim()))
+}
+
+// the end of a half-open range is one past the last day, so `1..26` is every day
+fn parse_end(day: &str) -> Result<u8, String> {
+    Some(parse_number(day)?)
+        .filter(|day| (2..=26).contains(day))
+        .ok_or(format!(
+            "'{}' is not a range end between 2 and 26",
+            day.trim()
+        ))
+}
+
+impl FromStr for DaySelector {
+    type Err = String;
+
+    fn from_str(spec: &str) -> Result<DaySelector, String> {
+        let mut days = vec![];
+
+        for part in spec.split(',') {
+            let range = match (part.split_once("..="), part.split_once("..")) {
+                (Some((from, to)), _) => parse_day(from)?..=parse_day(to)?,
+                (None, Some((from, to))) => parse_day(from)?..=parse_end(to)? - 1,
+                (None, None) => parse_day(part)?..=parse_day(part)?,
+            };
+
+            if range.is_empty() {
+                return Err(format!("'{}' is an empty range", part));
+            }
+
+            days.extend(range);
+        }
+
+        Ok(DaySelector(days.into_iter().sorted().dedup().collect()))
+    }
+}
+
+impl DaySelector {
+    pub fn days(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn contains(&self, day: u8) -> bool {
+        self.0.contains(&day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn days(spec: &str) -> Result<Vec<u8>, String> {
+        spec.parse::<DaySelector>()
+            .map(|selector| selector.days().to_vec())
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(days("5"), Ok(vec![5]));
+        assert_eq!(days("05"), Ok(vec![5]));
+        assert_eq!(days("1..=4"), Ok(vec![1, 2, 3, 4]));
+        assert_eq!(days("1..4"), Ok(vec![1, 2, 3]));
+        assert_eq!(days("24..26"), Ok(vec![24, 25]));
+        assert_eq!(days("12,3,7"), Ok(vec![3, 7, 12]));
+        assert_eq!(days("1..=3,2,25"), Ok(vec![1, 2, 3, 25]));
+        assert!(days("0").is_err());
+        assert!(days("26").is_err());
+        assert!(days("4..=2").is_err());
+        assert!(days("3..3").is_err());
+        assert!(days("1..27").is_err());
+        assert!(days("1..1").is_err());
+        assert_eq!(
+            days("1-25"),
+            Err("'1-25' is not a day, expected e.g. 5, 1..=10, 1..11 or 3,7,12".to_string())
+        );
+        assert!(days("").is_err());
+    }
+}
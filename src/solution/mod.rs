@@ -0,0 +1,208 @@
+use std::{any::Any, fmt::Debug, str::FromStr};
+
+use itertools::Itertools;
+
+pub trait Solution {
+    type Input: 'static;
+
+    // constants that differ between the examples and the real input, e.g. how many steps to walk
+    const PARAMS: &'static [Param] = &[];
+
+    // false for days with a single part, like the last day of each year - part_2 isn't run then
+    const HAS_PART_2: bool = true;
+
+    fn parse(input: &str) -> Self::Input;
+    fn part_1(input: &Self::Input, params: &Params) -> String;
+    fn part_2(input: &Self::Input, params: &Params) -> String;
+}
+
+// a named constant and its value for the real input
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: &'static str,
+}
+
+// values of a day's params, the defaults unless overridden with `--param name=value`
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Params(Vec<(&'static str, String)>);
+
+impl Params {
+    pub fn defaults(declared: &'static [Param]) -> Params {
+        Params(
+            declared
+                .iter()
+                .map(|param| (param.name, param.default.to_string()))
+                .collect(),
+        )
+    }
+
+    // only declared params can be overridden
+    pub fn new(
+        declared: &'static [Param],
+        overrides: &[(String, String)],
+    ) -> Result<Params, String> {
+        let mut params = Params::defaults(declared);
+
+        for (name, value) in overrides {
+            let Some((_, current)) = params.0.iter_mut().find(|(declared, _)| declared == name)
+            else {
+                if declared.is_empty() {
+                    return Err(format!("Unknown param {}, this day has none", name));
+                }
+
+                return Err(format!(
+                    "Unknown param {}, expected one of: {}",
+                    name,
+                    declared.iter().map(|param| param.name).join(", ")
+                ));
+            };
+
+            *current = value.clone();
+        }
+
+        Ok(params)
+    }
+
+    // panics if the param isn't declared or its value doesn't parse, like a bad input would
+    pub fn get<T>(&self, name: &str) -> T
+    where
+        T: FromStr,
+        <T as FromStr>::Err: Debug,
+    {
+        let Some((_, value)) = self.0.iter().find(|(declared, _)| *declared == name) else {
+            panic!("Undeclared param {}", name);
+        };
+
+        value
+            .parse()
+            .unwrap_or_else(|e| panic!("Invalid value {:?} for param {}: {:?}", value, name, e))
+    }
+}
+
+// `name=value`, as given to `--param`
+pub fn parse_param(s: &str) -> Result<(String, String), String> {
+    match s.split_once('=') {
+        Some((name, value)) if !name.trim().is_empty() => {
+            Ok((name.trim().to_string(), value.trim().to_string()))
+        }
+        _ => Err(format!("Expected name=value, got {:?}", s)),
+    }
+}
+
+// output of Day::parse, only meaningful to the day that produced it
+pub struct Parsed(Box<dyn Any>);
+
+// type-erased handle to a Solution, so days with different Input types can live in one registry
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub params: &'static [Param],
+    pub has_part_2: bool,
+    parse: fn(&str) -> Parsed,
+    part_1: fn(&Parsed, &Params) -> String,
+    part_2: fn(&Parsed, &Params) -> String,
+}
+
+fn parse<S: Solution>(input: &str) -> Parsed {
+    Parsed(Box::new(S::parse(input)))
+}
+
+fn part_1<S: Solution>(input: &Parsed, params: &Params) -> String {
+    S::part_1(input.0.downcast_ref::<S::Input>().unwrap(), params)
+}
+
+fn part_2<S: Solution>(input: &Parsed, params: &Params) -> String {
+    S::part_2(input.0.downcast_ref::<S::Input>().unwrap(), params)
+}
+
+impl Day {
+    pub const fn new<S: Solution>(year: u16, day: u8) -> Day {
+        Day {
+            year,
+            day,
+            params: S::PARAMS,
+            has_part_2: S::HAS_PART_2,
+            parse: parse::<S>,
+            part_1: part_1::<S>,
+            part_2: part_2::<S>,
+        }
+    }
+
+    pub fn year_str(&self) -> String {
+        self.year.to_string()
+    }
+
+    pub fn day_str(&self) -> String {
+        format!("{:02}", self.day)
+    }
+
+    pub fn name(&self) -> String {
+        format!("year{}_day{:02}", self.year, self.day)
+    }
+
+    // the values for the real input
+    pub fn default_params(&self) -> Params {
+        Params::defaults(self.params)
+    }
+
+    pub fn parse(&self, input: &str) -> Parsed {
+        (self.parse)(input)
+    }
+
+    pub fn part_1(&self, input: &Parsed, params: &Params) -> String {
+        (self.part_1)(input, params)
+    }
+
+    pub fn part_2(&self, input: &Parsed, params: &Params) -> String {
+        (self.part_2)(input, params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PARAMS: &[Param] = &[
+        Param {
+            name: "steps",
+            default: "64",
+        },
+        Param {
+            name: "min",
+            default: "7.5",
+        },
+    ];
+
+    #[test]
+    fn test_params() {
+        let defaults = Params::defaults(PARAMS);
+
+        assert_eq!(defaults.get::<usize>("steps"), 64);
+        assert_eq!(defaults.get::<f64>("min"), 7.5);
+
+        let params = Params::new(PARAMS, &[("steps".to_string(), "6".to_string())]).unwrap();
+
+        assert_eq!(params.get::<usize>("steps"), 6);
+        assert_eq!(params.get::<f64>("min"), 7.5);
+        assert_eq!(
+            Params::new(PARAMS, &[("step".to_string(), "6".to_string())]),
+            Err("Unknown param step, expected one of: steps, min".to_string())
+        );
+        assert_eq!(
+            Params::new(&[], &[("steps".to_string(), "6".to_string())]),
+            Err("Unknown param steps, this day has none".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_param() {
+        assert_eq!(
+            parse_param("steps=6"),
+            Ok(("steps".to_string(), "6".to_string()))
+        );
+        assert!(parse_param("steps").is_err());
+        assert!(parse_param("=6").is_err());
+    }
+}
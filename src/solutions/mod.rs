@@ -0,0 +1,69 @@
+use crate::solution::Day;
+
+// every solved day, in (year, day) order, grouped by the cargo feature that compiles the year
+//
+// `cargo run new <year> <day>` inserts new days here
+macro_rules! days {
+    ($($feature:literal => [$($module:ident: $year:literal, $day:literal;)*])*) => {
+        $($(#[cfg(feature = $feature)] pub mod $module;)*)*
+
+        pub const DAYS: &[Day] = &[$($(
+            #[cfg(feature = $feature)]
+            Day::new::<$module::Puzzle>($year, $day),
+        )*)*];
+    };
+}
+
+days! {
+    "year2015" => [
+        year2015_day01: 2015, 1;
+        year2015_day02: 2015, 2;
+        year2015_day03: 2015, 3;
+        year2015_day04: 2015, 4;
+    ]
+    "year2022" => [
+        year2022_day01: 2022, 1;
+        year2022_day02: 2022, 2;
+        year2022_day03: 2022, 3;
+        year2022_day04: 2022, 4;
+    ]
+    "year2023" => [
+        year2023_day01: 2023, 1;
+        year2023_day02: 2023, 2;
+        year2023_day03: 2023, 3;
+        year2023_day04: 2023, 4;
+        year2023_day05: 2023, 5;
+        year2023_day06: 2023, 6;
+        year2023_day07: 2023, 7;
+        year2023_day08: 2023, 8;
+        year2023_day09: 2023, 9;
+        year2023_day10: 2023, 10;
+        year2023_day11: 2023, 11;
+        year2023_day12: 2023, 12;
+        year2023_day13: 2023, 13;
+        year2023_day14: 2023, 14;
+        year2023_day15: 2023, 15;
+        year2023_day16: 2023, 16;
+        year2023_day17: 2023, 17;
+        year2023_day18: 2023, 18;
+        year2023_day19: 2023, 19;
+        year2023_day20: 2023, 20;
+        year2023_day21: 2023, 21;
+        year2023_day22: 2023, 22;
+        year2023_day23: 2023, 23;
+        year2023_day24: 2023, 24;
+        year2023_day25: 2023, 25;
+    ]
+    "year2024" => [
+        year2024_day01: 2024, 1;
+        year2024_day02: 2024, 2;
+        year2024_day03: 2024, 3;
+        year2024_day04: 2024, 4;
+        year2024_day05: 2024, 5;
+        year2024_day06: 2024, 6;
+    ]
+}
+
+pub fn find(year: u16, day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.year == year && d.day == day)
+}
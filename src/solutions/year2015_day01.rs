@@ -0,0 +1,50 @@
+use crate::solution::{Params, Solution};
+use itertools::{FoldWhile, Itertools};
+
+fn part_1(input: &str) -> String {
+    input
+        .chars()
+        .fold(0, |floor, ch| match ch {
+            '(' => floor + 1,
+            ')' => floor - 1,
+            _ => floor,
+        })
+        .to_string()
+}
+
+fn part_2(input: &str) -> String {
+    input
+        .chars()
+        .enumerate()
+        .fold_while(0, |floor, (idx, ch)| {
+            if floor == -1 {
+                FoldWhile::Done(idx as isize)
+            } else {
+                match ch {
+                    '(' => FoldWhile::Continue(floor + 1),
+                    ')' => FoldWhile::Continue(floor - 1),
+                    _ => panic!(),
+                }
+            }
+        })
+        .into_inner()
+        .to_string()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = String;
+
+    fn parse(input: &str) -> String {
+        input.to_string()
+    }
+
+    fn part_1(input: &String, _: &Params) -> String {
+        part_1(input)
+    }
+
+    fn part_2(input: &String, _: &Params) -> String {
+        part_2(input)
+    }
+}
@@ -1,6 +1,4 @@
-use std::time::Instant;
-
-use aoc_rust::read_input;
+use crate::solution::{Params, Solution};
 use itertools::Itertools;
 
 fn part_1(input: &str) -> String {
@@ -47,45 +45,20 @@ fn part_2(input: &str) -> String {
         .to_string()
 }
 
-fn main() {
-    let input = read_input("2015", "02");
-
-    let start_part_1 = Instant::now();
-    let part_1_result = part_1(&input);
-    let part_1_time = start_part_1.elapsed();
-
-    println!();
-    println!("Part 1: {} ({:?})", part_1_result, part_1_time);
+pub struct Puzzle;
 
-    let start_part_2 = Instant::now();
-    let part_2_result = part_2(&input);
-    let part_2_time = start_part_2.elapsed();
-
-    println!("Part 2: {} ({:?})", part_2_result, part_2_time);
-}
+impl Solution for Puzzle {
+    type Input = String;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_1() {
-        let input = r"
-2x3x4
-1x1x10"
-            .trim();
-
-        assert_eq!(part_1(input), "101");
+    fn parse(input: &str) -> String {
+        input.to_string()
     }
 
-    #[test]
-    fn test_part_2() {
-        let input = r"
-
-3x4x2
-10x1x1"
-            .trim();
+    fn part_1(input: &String, _: &Params) -> String {
+        part_1(input)
+    }
 
-        assert_eq!(part_2(input), "48");
+    fn part_2(input: &String, _: &Params) -> String {
+        part_2(input)
     }
 }
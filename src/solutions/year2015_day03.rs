@@ -0,0 +1,71 @@
+use std::collections::HashSet;
+
+use crate::{
+    ivec2::IVec2,
+    solution::{Params, Solution},
+};
+
+fn step(ch: char) -> IVec2 {
+    match ch {
+        '>' => IVec2::new(1, 0),
+        '<' => IVec2::new(-1, 0),
+        '^' => IVec2::new(0, 1),
+        'v' => IVec2::new(0, -1),
+        _ => IVec2::ZERO,
+    }
+}
+
+fn part_1(input: &str) -> String {
+    let mut visited: HashSet<IVec2> = HashSet::new();
+    visited.insert(IVec2::ZERO);
+
+    let mut current = IVec2::ZERO;
+
+    for ch in input.chars() {
+        current += step(ch);
+
+        visited.insert(current);
+    }
+
+    visited.len().to_string()
+}
+
+fn part_2(input: &str) -> String {
+    let mut visited: HashSet<IVec2> = HashSet::new();
+    visited.insert(IVec2::ZERO);
+
+    let mut current_a = IVec2::ZERO;
+    let mut current_b = IVec2::ZERO;
+
+    for (idx, ch) in input.chars().enumerate() {
+        let current = if idx % 2 == 0 {
+            &mut current_a
+        } else {
+            &mut current_b
+        };
+
+        *current += step(ch);
+
+        visited.insert(*current);
+    }
+
+    visited.len().to_string()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = String;
+
+    fn parse(input: &str) -> String {
+        input.to_string()
+    }
+
+    fn part_1(input: &String, _: &Params) -> String {
+        part_1(input)
+    }
+
+    fn part_2(input: &String, _: &Params) -> String {
+        part_2(input)
+    }
+}
@@ -0,0 +1,51 @@
+use crate::solution::{Params, Solution};
+
+fn part_1(input: &str) -> String {
+    let mut n = 0;
+
+    loop {
+        let s = format!("{input}{}", n);
+        let hash = md5::compute(&s);
+        let hash_str = format!("{:x}", hash);
+
+        if &hash_str[0..5] == "00000" {
+            return n.to_string();
+        }
+
+        n += 1;
+    }
+}
+
+fn part_2(input: &str) -> String {
+    let mut n = 0;
+
+    loop {
+        let s = format!("{input}{}", n);
+        let hash = md5::compute(&s);
+        let hash_str = format!("{:x}", hash);
+
+        if &hash_str[0..6] == "000000" {
+            return n.to_string();
+        }
+
+        n += 1;
+    }
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = String;
+
+    fn parse(input: &str) -> String {
+        input.to_string()
+    }
+
+    fn part_1(input: &String, _: &Params) -> String {
+        part_1(input)
+    }
+
+    fn part_2(input: &String, _: &Params) -> String {
+        part_2(input)
+    }
+}
@@ -1,4 +1,4 @@
-use aoc_rust::read_input;
+use crate::solution::{Params, Solution};
 use itertools::Itertools;
 
 fn part_1(input: &str) -> String {
@@ -28,58 +28,20 @@ fn part_2(input: &str) -> String {
         .to_string();
 }
 
-fn main() {
-    let input = read_input("2022", "01");
+pub struct Puzzle;
 
-    println!("Part 1: {}", part_1(&input));
-    println!("Part 2: {}", part_2(&input));
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_1() {
-        let input = r"
-1000
-2000
-3000
-
-4000
-
-5000
-6000
-
-7000
-8000
-9000
+impl Solution for Puzzle {
+    type Input = String;
 
-10000"
-            .trim();
-
-        assert_eq!(part_1(input), "24000");
+    fn parse(input: &str) -> String {
+        input.to_string()
     }
 
-    #[test]
-    fn test_part_2() {
-        let input = r"
-1000
-2000
-3000
-
-4000
-
-5000
-6000
-
-7000
-8000
-9000
-
-10000"
-            .trim();
+    fn part_1(input: &String, _: &Params) -> String {
+        part_1(input)
+    }
 
-        assert_eq!(part_2(input), "45000");
+    fn part_2(input: &String, _: &Params) -> String {
+        part_2(input)
     }
 }
@@ -1,4 +1,4 @@
-use aoc_rust::read_input;
+use crate::solution::{Params, Solution};
 
 enum Shape {
     Rock,
@@ -77,7 +77,7 @@ fn part_1(input: &str) -> String {
         .lines()
         .map(|line| {
             let ours = Shape::from_char(line.chars().nth(2).unwrap());
-            let theirs = Shape::from_char(line.chars().nth(0).unwrap());
+            let theirs = Shape::from_char(line.chars().next().unwrap());
 
             return ours.versus(&theirs).score() + ours.score();
         })
@@ -89,7 +89,7 @@ fn part_2(input: &str) -> String {
     return input
         .lines()
         .map(|line| {
-            let theirs = Shape::from_char(line.chars().nth(0).unwrap());
+            let theirs = Shape::from_char(line.chars().next().unwrap());
             let outcome = Outcome::from_char(line.chars().nth(2).unwrap());
 
             let ours = match outcome {
@@ -112,38 +112,20 @@ fn part_2(input: &str) -> String {
         .to_string();
 }
 
-fn main() {
-    let input = read_input("2022", "02");
+pub struct Puzzle;
 
-    println!("Part 1: {}", part_1(&input));
-    println!("Part 2: {}", part_2(&input));
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
+impl Solution for Puzzle {
+    type Input = String;
 
-    #[test]
-    fn test_part_1() {
-        let input = r"
-A Y
-B X
-C Z
-        "
-        .trim();
-
-        assert_eq!(part_1(input), "15");
+    fn parse(input: &str) -> String {
+        input.to_string()
     }
 
-    #[test]
-    fn test_part_2() {
-        let input = r"
-A Y
-B X
-C Z
-        "
-        .trim();
+    fn part_1(input: &String, _: &Params) -> String {
+        part_1(input)
+    }
 
-        assert_eq!(part_2(input), "12");
+    fn part_2(input: &String, _: &Params) -> String {
+        part_2(input)
     }
 }
@@ -1,4 +1,4 @@
-use aoc_rust::read_input;
+use crate::solution::{Params, Solution};
 use itertools::Itertools;
 
 fn priority(c: char) -> usize {
@@ -42,44 +42,20 @@ fn part_2(input: &str) -> String {
         .to_string();
 }
 
-fn main() {
-    let input = read_input("2022", "03");
+pub struct Puzzle;
 
-    println!("Part 1: {}", part_1(&input));
-    println!("Part 2: {}", part_2(&input));
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
+impl Solution for Puzzle {
+    type Input = String;
 
-    #[test]
-    fn test_part_1() {
-        let input = r"
-vJrwpWtwJgWrhcsFMMfFFhFp
-jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
-PmmdzqPrVvPwwTWBwg
-wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
-ttgJtRGJQctTZtZT
-CrZsJsPPZsGzwwsLwLmpwMDw
-        "
-        .trim();
-
-        assert_eq!(part_1(input), "157");
+    fn parse(input: &str) -> String {
+        input.to_string()
     }
 
-    #[test]
-    fn test_part_2() {
-        let input = r"
-vJrwpWtwJgWrhcsFMMfFFhFp
-jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
-PmmdzqPrVvPwwTWBwg
-wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
-ttgJtRGJQctTZtZT
-CrZsJsPPZsGzwwsLwLmpwMDw
-        "
-        .trim();
+    fn part_1(input: &String, _: &Params) -> String {
+        part_1(input)
+    }
 
-        assert_eq!(part_2(input), "70");
+    fn part_2(input: &String, _: &Params) -> String {
+        part_2(input)
     }
 }
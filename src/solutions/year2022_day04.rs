@@ -1,4 +1,4 @@
-use aoc_rust::read_input;
+use crate::solution::{Params, Solution};
 use itertools::Itertools;
 
 fn parse_range(s: &str) -> (usize, usize) {
@@ -13,7 +13,7 @@ fn parse_range(s: &str) -> (usize, usize) {
 fn part_1(input: &str) -> String {
     input
         .lines()
-        .map(|line| {
+        .flat_map(|line| {
             line.split(',')
                 .tuples()
                 .map(|(a, b)| (parse_range(a), parse_range(b)))
@@ -25,7 +25,6 @@ fn part_1(input: &str) -> String {
                     }
                 })
         })
-        .flatten()
         .sum::<usize>()
         .to_string()
 }
@@ -33,55 +32,30 @@ fn part_1(input: &str) -> String {
 fn part_2(input: &str) -> String {
     input
         .lines()
-        .map(|line| {
+        .flat_map(|line| {
             line.split(',')
                 .tuples()
                 .map(|(a, b)| (parse_range(a), parse_range(b)))
                 .map(|((a, b), (c, d))| if a <= d && b >= c { 1 } else { 0 })
         })
-        .flatten()
         .sum::<usize>()
         .to_string()
 }
 
-fn main() {
-    let input = read_input("2022", "04");
+pub struct Puzzle;
 
-    println!("Part 1: {}", part_1(&input));
-    println!("Part 2: {}", part_2(&input));
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
+impl Solution for Puzzle {
+    type Input = String;
 
-    #[test]
-    fn test_part_1() {
-        let input = r"
-2-4,6-8
-2-3,4-5
-5-7,7-9
-2-8,3-7
-6-6,4-6
-2-6,4-8
-        "
-        .trim();
-
-        assert_eq!(part_1(input), "2");
+    fn parse(input: &str) -> String {
+        input.to_string()
     }
 
-    #[test]
-    fn test_part_2() {
-        let input = r"
-2-4,6-8
-2-3,4-5
-5-7,7-9
-2-8,3-7
-6-6,4-6
-2-6,4-8
-        "
-        .trim();
+    fn part_1(input: &String, _: &Params) -> String {
+        part_1(input)
+    }
 
-        assert_eq!(part_2(input), "4");
+    fn part_2(input: &String, _: &Params) -> String {
+        part_2(input)
     }
 }
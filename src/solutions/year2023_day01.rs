@@ -1,7 +1,6 @@
-use aoc_rust::read_input;
+use crate::solution::{Params, Solution};
 use itertools::FoldWhile::{Continue, Done};
 use itertools::Itertools;
-use std::time::Instant;
 
 fn part_1(input: &str) -> String {
     input
@@ -80,63 +79,20 @@ fn part_2(input: &str) -> String {
         .to_string()
 }
 
-fn main() {
-    let input = read_input("2023", "01");
+pub struct Puzzle;
 
-    let start_part_1 = Instant::now();
-    let part_1_result = part_1(&input);
-    let part_1_time = start_part_1.elapsed();
+impl Solution for Puzzle {
+    type Input = String;
 
-    println!();
-    println!("Part 1: {} ({:?})", part_1_result, part_1_time);
-
-    let start_part_2 = Instant::now();
-    let part_2_result = part_2(&input);
-    let part_2_time = start_part_2.elapsed();
-
-    println!("Part 2: {} ({:?})", part_2_result, part_2_time);
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_1() {
-        let input = r"
-1abc2
-pqr3stu8vwx
-a1b2c3d4e5f
-treb7uchet
-        "
-        .trim();
-
-        assert_eq!(part_1(input), "142");
+    fn parse(input: &str) -> String {
+        input.to_string()
     }
 
-    #[test]
-    fn test_part_2() {
-        let input = r"
-two1nine
-eightwothree
-abcone2threexyz
-xtwone3four
-4nineeightseven2
-zoneight234
-7pqrstsixteen
-        "
-        .trim();
-
-        assert_eq!(part_2(input), "281");
+    fn part_1(input: &String, _: &Params) -> String {
+        part_1(input)
     }
 
-    #[test]
-    fn test_part_2_2() {
-        let input = r"
-zerotwozerotwone
-        "
-        .trim();
-
-        assert_eq!(part_2(input), "21");
+    fn part_2(input: &String, _: &Params) -> String {
+        part_2(input)
     }
 }
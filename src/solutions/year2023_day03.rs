@@ -1,6 +1,9 @@
-use std::{collections::HashSet, time::Instant};
+use std::collections::HashSet;
 
-use aoc_rust::read_input;
+use crate::{
+    solution::{Params, Solution},
+    uvec2::UVec2,
+};
 use itertools::Itertools;
 
 fn is_engine_part(ch: char) -> bool {
@@ -16,7 +19,7 @@ fn get_coords_of_adjacent_chars_that_match_predicate(
     x: usize,
     y: usize,
     predicate: fn(char) -> bool,
-) -> Vec<(usize, usize)> {
+) -> Vec<UVec2> {
     (x as isize - 1..=x as isize + 1)
         .flat_map(|x| (y as isize - 1..=y as isize + 1).map(move |y| (x, y)))
         .filter(|(x, y)| {
@@ -26,7 +29,7 @@ fn get_coords_of_adjacent_chars_that_match_predicate(
                 && *y < matrix.len() as isize
                 && predicate(matrix[*y as usize][*x as usize])
         })
-        .map(|(x, y)| (x as usize, y as usize))
+        .map(|(x, y)| UVec2::new(x as usize, y as usize))
         .collect_vec()
 }
 
@@ -96,7 +99,7 @@ fn part_2(input: &str) -> String {
     for y in 0..matrix.len() {
         let line = &matrix[y];
         let mut num_str = String::new();
-        let mut adjacent_gears: HashSet<(usize, usize)> = HashSet::new();
+        let mut adjacent_gears: HashSet<UVec2> = HashSet::new();
 
         for x in 0..=line.len() {
             let ch = if x < line.len() { line[x] } else { '.' };
@@ -104,19 +107,17 @@ fn part_2(input: &str) -> String {
             if ch.is_numeric() {
                 num_str.push(ch);
 
-                for (x, y) in
-                    get_coords_of_adjacent_chars_that_match_predicate(&matrix, x, y, is_gear)
-                {
-                    adjacent_gears.insert((x, y));
-                }
+                adjacent_gears.extend(get_coords_of_adjacent_chars_that_match_predicate(
+                    &matrix, x, y, is_gear,
+                ));
             } else if !num_str.is_empty() {
                 let num = num_str.parse::<usize>().unwrap();
 
-                for (x, y) in adjacent_gears.iter() {
-                    gear_counts[*y][*x] += 1;
+                for &UVec2 { x, y } in adjacent_gears.iter() {
+                    gear_counts[y][x] += 1;
 
-                    if gear_counts[*y][*x] <= 2 {
-                        gear_ratios[*y][*x] *= num;
+                    if gear_counts[y][x] <= 2 {
+                        gear_ratios[y][x] *= num;
                     }
                 }
 
@@ -137,60 +138,20 @@ fn part_2(input: &str) -> String {
     return sum.to_string();
 }
 
-fn main() {
-    let input = read_input("2023", "03");
-
-    let start_part_1 = Instant::now();
-    let part_1_result = part_1(&input);
-    let part_1_time = start_part_1.elapsed();
-
-    println!();
-    println!("Part 1: {} ({:?})", part_1_result, part_1_time);
+pub struct Puzzle;
 
-    let start_part_2 = Instant::now();
-    let part_2_result = part_2(&input);
-    let part_2_time = start_part_2.elapsed();
+impl Solution for Puzzle {
+    type Input = String;
 
-    println!("Part 2: {} ({:?})", part_2_result, part_2_time);
-}
+    fn parse(input: &str) -> String {
+        input.to_string()
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_1() {
-        let input = r"
-467..114..
-...*......
-..35..633.
-......#...
-617*......
-.....+.58.
-..592.....
-......755.
-...$.*....
-.664.598.."
-            .trim();
-
-        assert_eq!(part_1(input), "4361");
+    fn part_1(input: &String, _: &Params) -> String {
+        part_1(input)
     }
 
-    #[test]
-    fn test_part_2() {
-        let input = r"
-467..114..
-...*......
-..35..633.
-......#...
-617*......
-.....+.58.
-..592.....
-......755.
-...$.*....
-.664.598.."
-            .trim();
-
-        assert_eq!(part_2(input), "467835");
+    fn part_2(input: &String, _: &Params) -> String {
+        part_2(input)
     }
 }
@@ -0,0 +1,66 @@
+use crate::solution::{Params, Solution};
+use itertools::Itertools;
+
+fn get_score(card: &str) -> usize {
+    let (winning_str, own_str) = card.split_once(": ").unwrap().1.split_once(" | ").unwrap();
+
+    let winning_nums = winning_str
+        .split_whitespace()
+        .map(|s| s.parse::<usize>().unwrap())
+        .collect_vec();
+
+    own_str
+        .split_whitespace()
+        .map(|s| s.parse::<usize>().unwrap())
+        .filter(|n| winning_nums.contains(n))
+        .count()
+}
+
+fn part_1(input: &str) -> String {
+    return input
+        .lines()
+        .map(|line| match get_score(line) {
+            0 => 0,
+            score => usize::pow(2, score as u32 - 1),
+        })
+        .sum::<usize>()
+        .to_string();
+}
+
+fn part_2(input: &str) -> String {
+    input
+        .lines()
+        .map(get_score)
+        .enumerate()
+        .fold(
+            input.lines().map(|_| 1).collect_vec(),
+            |mut card_counts, (i, score)| {
+                for j in 0..score {
+                    card_counts[i + j + 1] += card_counts[i];
+                }
+
+                card_counts
+            },
+        )
+        .iter()
+        .sum::<usize>()
+        .to_string()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = String;
+
+    fn parse(input: &str) -> String {
+        input.to_string()
+    }
+
+    fn part_1(input: &String, _: &Params) -> String {
+        part_1(input)
+    }
+
+    fn part_2(input: &String, _: &Params) -> String {
+        part_2(input)
+    }
+}
@@ -1,6 +1,4 @@
-use std::time::Instant;
-
-use aoc_rust::read_input;
+use crate::solution::{Params, Solution};
 use itertools::Itertools;
 
 fn parse_range(line: &str) -> Option<(usize, usize, usize)> {
@@ -118,7 +116,7 @@ fn traverse(
             if let Some(overlap_range) = overlap_range {
                 return Some((
                     original_range.0 + (overlap_range.0 - range.0),
-                    original_range.1 + (overlap_range.1 - range.1),
+                    original_range.1 - (range.1 - overlap_range.1),
                 ));
             }
         }
@@ -143,7 +141,7 @@ fn traverse(
                 ),
                 (
                     original_range.0 + (overlap_range.0 - range.0),
-                    original_range.1 + (overlap_range.1 - range.1),
+                    original_range.1 - (range.1 - overlap_range.1),
                 ),
             );
 
@@ -196,7 +194,7 @@ fn part_2(input: &str) -> String {
                 ranges
             });
 
-        entries.sort_by(|a, b| a.output_range.unwrap().0.cmp(&b.output_range.unwrap().0));
+        entries.sort_by_key(|a| a.output_range.unwrap().0);
 
         let smallest = entries.first().unwrap().output_range.unwrap().0;
         let largest = entries.last().unwrap().output_range.unwrap().1;
@@ -242,117 +240,28 @@ fn part_2(input: &str) -> String {
         .to_string()
 }
 
-fn main() {
-    let input = read_input("2023", "05");
+pub struct Puzzle;
 
-    let start_part_1 = Instant::now();
-    let part_1_result = part_1(&input);
-    let part_1_time = start_part_1.elapsed();
+impl Solution for Puzzle {
+    type Input = String;
 
-    println!();
-    println!("Part 1: {} ({:?})", part_1_result, part_1_time);
+    fn parse(input: &str) -> String {
+        input.to_string()
+    }
 
-    let start_part_2 = Instant::now();
-    let part_2_result = part_2(&input);
-    let part_2_time = start_part_2.elapsed();
+    fn part_1(input: &String, _: &Params) -> String {
+        part_1(input)
+    }
 
-    println!("Part 2: {} ({:?})", part_2_result, part_2_time);
+    fn part_2(input: &String, _: &Params) -> String {
+        part_2(input)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_1() {
-        let input = r"
-seeds: 79 14 55 13
-
-seed-to-soil map:
-50 98 2
-52 50 48
-0 50 50
-
-soil-to-fertilizer map:
-0 15 37
-37 52 2
-39 0 15
-
-fertilizer-to-water map:
-49 53 8
-0 11 42
-42 0 7
-57 7 4
-
-water-to-light map:
-88 18 7
-18 25 70
-0 18 18
-
-light-to-temperature map:
-45 77 23
-81 45 19
-68 64 13
-0 45 45
-
-temperature-to-humidity map:
-0 69 1
-1 0 69
-
-humidity-to-location map:
-60 56 37
-56 93 4
-        "
-        .trim();
-
-        assert_eq!(part_1(input), "35");
-    }
-
-    #[test]
-    fn test_part_2() {
-        let input = r"
-seeds: 79 14 55 13
-
-seed-to-soil map:
-50 98 2
-52 50 48
-0 0 50
-
-soil-to-fertilizer map:
-0 15 37
-37 52 2
-39 0 15
-
-fertilizer-to-water map:
-49 53 8
-0 11 42
-42 0 7
-57 7 4
-
-water-to-light map:
-88 18 7
-18 25 70
-0 0 18
-
-light-to-temperature map:
-45 77 23
-81 45 19
-68 64 13
-0 0 45
-
-temperature-to-humidity map:
-0 69 1
-1 0 69
-
-humidity-to-location map:
-60 56 37
-56 93 4
-        "
-        .trim();
-
-        assert_eq!(part_2(input), "");
-    }
-
     #[test]
     fn test_collide() {
         assert_eq!(collide(&(0, 5), &(6, 10)), (None, vec![(0, 5)]));
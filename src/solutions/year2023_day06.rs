@@ -1,7 +1,6 @@
 use itertools::Itertools;
-use std::time::Instant;
 
-use aoc_rust::read_input;
+use crate::solution::{Params, Solution};
 
 fn solve_quadratic(a: f64, b: f64, c: f64) -> (f64, f64) {
     return (
@@ -11,7 +10,7 @@ fn solve_quadratic(a: f64, b: f64, c: f64) -> (f64, f64) {
 }
 
 fn get_solutions_count(time: usize, distance: usize) -> usize {
-    let a = -1 as f64;
+    let a = -1_f64;
     let b = time as f64;
     // add some small n so that we actually beat the record and not match it
     let c = -(distance as f64 + 0.01);
@@ -59,46 +58,20 @@ fn part_2(input: &str) -> usize {
     )
 }
 
-fn main() {
-    let input = read_input("2023", "06");
+pub struct Puzzle;
 
-    let start_part_1 = Instant::now();
-    let part_1_result = part_1(&input);
-    let part_1_time = start_part_1.elapsed();
+impl Solution for Puzzle {
+    type Input = String;
 
-    println!();
-    println!("Part 1: {} ({:?})", part_1_result, part_1_time);
-
-    let start_part_2 = Instant::now();
-    let part_2_result = part_2(&input);
-    let part_2_time = start_part_2.elapsed();
-
-    println!("Part 2: {} ({:?})", part_2_result, part_2_time);
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_1() {
-        let input = r"
-Time:      7  15   30
-Distance:  9  40  200
-        "
-        .trim();
-
-        assert_eq!(part_1(input), 288);
+    fn parse(input: &str) -> String {
+        input.to_string()
     }
 
-    #[test]
-    fn test_part_2() {
-        let input = r"
-Time:      7  15   30
-Distance:  9  40  200
-        "
-        .trim();
+    fn part_1(input: &String, _: &Params) -> String {
+        part_1(input).to_string()
+    }
 
-        assert_eq!(part_2(input), 71503);
+    fn part_2(input: &String, _: &Params) -> String {
+        part_2(input).to_string()
     }
 }
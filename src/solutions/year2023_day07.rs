@@ -1,6 +1,4 @@
-use std::time::Instant;
-
-use aoc_rust::read_input;
+use crate::solution::{Params, Solution};
 use itertools::Itertools;
 
 fn quantize_card(card: char, joker: bool) -> usize {
@@ -78,11 +76,11 @@ fn quantize_hand(hand: &str, joker: bool) -> usize {
         .map(|card| quantize_card(card, joker))
         .enumerate()
         // 14 -> max card score
-        .map(|(idx, card_score)| (14 as usize).pow(5 - idx as u32) as usize * card_score)
+        .map(|(idx, card_score)| 14_usize.pow(5 - idx as u32) * card_score)
         .sum::<usize>();
 
     // base must be large enough to not have collisions between different hand ranks
-    (10 as usize).pow(hand_rank + 10) + hand_sum
+    10_usize.pow(hand_rank + 10) + hand_sum
 }
 
 fn part_1(input: &str) -> String {
@@ -119,52 +117,20 @@ fn part_2(input: &str) -> String {
         .to_string()
 }
 
-fn main() {
-    let input = read_input("2023", "07");
-
-    let start_part_1 = Instant::now();
-    let part_1_result = part_1(&input);
-    let part_1_time = start_part_1.elapsed();
-
-    println!();
-    println!("Part 1: {} ({:?})", part_1_result, part_1_time);
+pub struct Puzzle;
 
-    let start_part_2 = Instant::now();
-    let part_2_result = part_2(&input);
-    let part_2_time = start_part_2.elapsed();
+impl Solution for Puzzle {
+    type Input = String;
 
-    println!("Part 2: {} ({:?})", part_2_result, part_2_time);
-}
+    fn parse(input: &str) -> String {
+        input.to_string()
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_1() {
-        let input = r"
-32T3K 765
-T55J5 684
-KK677 28
-KTJJT 220
-QQQJA 483
-        "
-        .trim();
-
-        assert_eq!(part_1(input), "6440");
+    fn part_1(input: &String, _: &Params) -> String {
+        part_1(input)
     }
 
-    #[test]
-    fn test_part_2() {
-        let input = r"
-32T3K 765
-T55J5 684
-KK677 28
-KTJJT 220
-QQQJA 483
-        "
-        .trim();
-
-        assert_eq!(part_2(input), "5905");
+    fn part_2(input: &String, _: &Params) -> String {
+        part_2(input)
     }
 }
@@ -1,6 +1,6 @@
-use std::{collections::HashMap, time::Instant};
+use std::collections::HashMap;
 
-use aoc_rust::read_input;
+use crate::solution::{Params, Solution};
 use itertools::Itertools;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
@@ -21,7 +21,7 @@ struct Node<'a> {
     right_id: &'a str,
 }
 
-fn nodes_from_str(s: &str) -> Vec<Node> {
+fn nodes_from_str(s: &str) -> Vec<Node<'_>> {
     let mut nodes: Vec<Node> = Vec::new();
     // helper hash map to build out `nodes`
     let mut indices: HashMap<&str, usize> = HashMap::new();
@@ -140,83 +140,25 @@ fn part_2(input: &str) -> String {
                 }
             }
         })
-        .reduce_with(|a, b| lcm(a, b))
+        .reduce_with(lcm)
         .unwrap()
         .to_string()
 }
 
-fn main() {
-    let input = read_input("2023", "08");
+pub struct Puzzle;
 
-    let start_part_1 = Instant::now();
-    let part_1_result = part_1(&input);
-    let part_1_time = start_part_1.elapsed();
+impl Solution for Puzzle {
+    type Input = String;
 
-    println!("Part 1: {} ({:?})", part_1_result, part_1_time);
-
-    let start_part_2 = Instant::now();
-    let part_2_result = part_2(&input);
-    let part_2_time = start_part_2.elapsed();
-
-    println!("Part 2: {} ({:?})", part_2_result, part_2_time);
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_1_1() {
-        assert_eq!(
-            part_1(
-                r"
-RL
-
-AAA = (BBB, CCC)
-BBB = (DDD, EEE)
-CCC = (ZZZ, GGG)
-DDD = (DDD, DDD)
-EEE = (EEE, EEE)
-GGG = (GGG, GGG)
-ZZZ = (ZZZ, ZZZ)"
-                    .trim()
-            ),
-            "2"
-        );
+    fn parse(input: &str) -> String {
+        input.to_string()
     }
 
-    #[test]
-    fn test_part_1_2() {
-        assert_eq!(
-            part_1(
-                r"
-LLR
-
-AAA = (BBB, BBB)
-BBB = (AAA, ZZZ)
-ZZZ = (ZZZ, ZZZ)"
-                    .trim()
-            ),
-            "6"
-        );
+    fn part_1(input: &String, _: &Params) -> String {
+        part_1(input)
     }
 
-    #[test]
-    fn test_part_2() {
-        let input = r"
-LR
-
-11A = (11B, XXX)
-11B = (XXX, 11Z)
-11Z = (11B, XXX)
-22A = (22B, XXX)
-22B = (22C, 22C)
-22C = (22Z, 22Z)
-22Z = (22B, 22B)
-XXX = (XXX, XXX)
-        "
-        .trim();
-
-        assert_eq!(part_2(input), "6");
+    fn part_2(input: &String, _: &Params) -> String {
+        part_2(input)
     }
 }
@@ -1,6 +1,4 @@
-use std::time::Instant;
-
-use aoc_rust::read_input;
+use crate::solution::{Params, Solution};
 use itertools::Itertools;
 
 fn predict_next(row: Vec<isize>) -> isize {
@@ -37,7 +35,7 @@ fn predict_next(row: Vec<isize>) -> isize {
         to_add = new_el;
     }
 
-    rows[0].last().unwrap().clone()
+    *rows[0].last().unwrap()
 }
 
 fn part_1(input: &str) -> String {
@@ -69,48 +67,20 @@ fn part_2(input: &str) -> String {
         .to_string()
 }
 
-fn main() {
-    let input = read_input("2023", "09");
-
-    let start_part_1 = Instant::now();
-    let part_1_result = part_1(&input);
-    let part_1_time = start_part_1.elapsed();
-
-    println!();
-    println!("Part 1: {} ({:?})", part_1_result, part_1_time);
-
-    let start_part_2 = Instant::now();
-    let part_2_result = part_2(&input);
-    let part_2_time = start_part_2.elapsed();
-
-    println!("Part 2: {} ({:?})", part_2_result, part_2_time);
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub struct Puzzle;
 
-    #[test]
-    fn test_part_1() {
-        let input = r"
-0 3 6 9 12 15
-1 3 6 10 15 21
-10 13 16 21 30 45
-        "
-        .trim();
+impl Solution for Puzzle {
+    type Input = String;
 
-        assert_eq!(part_1(input), "114");
+    fn parse(input: &str) -> String {
+        input.to_string()
     }
 
-    #[test]
-    fn test_part_2() {
-        let input = r"
-0 3 6 9 12 15
-1 3 6 10 15 21
-10 13 16 21 30 45
-        "
-        .trim();
+    fn part_1(input: &String, _: &Params) -> String {
+        part_1(input)
+    }
 
-        assert_eq!(part_2(input), "2");
+    fn part_2(input: &String, _: &Params) -> String {
+        part_2(input)
     }
 }
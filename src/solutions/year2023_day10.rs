@@ -1,6 +1,4 @@
-use std::time::Instant;
-
-use aoc_rust::read_input;
+use crate::solution::{Params, Solution};
 
 fn parse_input(input: &str) -> (Vec<Vec<u8>>, (usize, usize)) {
     let mut start_pos: (usize, usize) = (0, 0);
@@ -70,19 +68,19 @@ fn parse_input(input: &str) -> (Vec<Vec<u8>>, (usize, usize)) {
     // fill in S
     matrix[start_pos.1 + 1][start_pos.0 + 1] = 1;
 
-    if matrix[start_pos.1 + 1][start_pos.0 - 1] == 1 {
+    if start_pos.0 > 0 && matrix[start_pos.1 + 1][start_pos.0 - 1] == 1 {
         matrix[start_pos.1 + 1][start_pos.0] = 1;
     }
 
-    if matrix[start_pos.1 + 1][start_pos.0 + 3] == 1 {
+    if start_pos.0 + 3 < width * 3 && matrix[start_pos.1 + 1][start_pos.0 + 3] == 1 {
         matrix[start_pos.1 + 1][start_pos.0 + 2] = 1;
     }
 
-    if matrix[start_pos.1 - 1][start_pos.0 + 1] == 1 {
+    if start_pos.1 > 0 && matrix[start_pos.1 - 1][start_pos.0 + 1] == 1 {
         matrix[start_pos.1][start_pos.0 + 1] = 1;
     }
 
-    if matrix[start_pos.1 + 3][start_pos.0 + 1] == 1 {
+    if start_pos.1 + 3 < height * 3 && matrix[start_pos.1 + 3][start_pos.0 + 1] == 1 {
         matrix[start_pos.1 + 2][start_pos.0 + 1] = 1;
     }
 
@@ -137,14 +135,13 @@ fn find_loop(
     }
 }
 
-fn part_1(input: &str) -> String {
-    let (matrix, start_pos) = parse_input(input);
+fn part_1((matrix, start_pos): &(Vec<Vec<u8>>, (usize, usize))) -> String {
     let mut loop_path: Vec<(usize, usize)> = vec![];
 
     let start_center_pos = (start_pos.0 + 1, start_pos.1 + 1);
 
     find_loop(
-        &matrix,
+        matrix,
         start_center_pos,
         start_center_pos,
         start_center_pos,
@@ -156,7 +153,7 @@ fn part_1(input: &str) -> String {
 
 fn fill(scaled_matrix: &mut Vec<Vec<u8>>, pos: (usize, usize), fill_with: u8, avoid: u8) {
     let (x, y) = pos;
-    scaled_matrix[y as usize][x as usize] = fill_with;
+    scaled_matrix[y][x] = fill_with;
 
     if x < scaled_matrix[y].len() - 1
         && scaled_matrix[y][(x) + 1] != avoid
@@ -181,8 +178,8 @@ fn fill(scaled_matrix: &mut Vec<Vec<u8>>, pos: (usize, usize), fill_with: u8, av
     }
 }
 
-fn part_2(input: &str) -> String {
-    let (mut matrix, start_pos) = parse_input(input);
+fn part_2((matrix, start_pos): &(Vec<Vec<u8>>, (usize, usize))) -> String {
+    let mut matrix = matrix.clone();
     let mut loop_path: Vec<(usize, usize)> = vec![];
 
     let start_center_pos = (start_pos.0 + 1, start_pos.1 + 1);
@@ -202,26 +199,19 @@ fn part_2(input: &str) -> String {
         matrix[y][x] = loop_marker;
     }
 
-    // on the row we're certain there's a part of the loop (starting point row)
-    // -> we're looking for a cell which comes after a part of the loop
-    // we're certain that this cell is INSIDE the loop, because we're starting from the edge
-    // note: if the starting point is actually the corner of the loop,
-    // and there's no part of the loop to the left or right of it, this won't work
-    // but i've not seen such inputs tbh :D
-    let inside_loop_pos = (
-        matrix[start_center_pos.1]
-            .iter()
-            .enumerate()
-            .find_map(|(x, &n)| {
-                if x < matrix[0].len() - 1 && n == 2 && matrix[start_center_pos.1][x + 1] != 2 {
-                    Some(x + 1)
-                } else {
-                    None
-                }
-            })
-            .unwrap(),
-        start_center_pos.1,
-    );
+    // the top row of each scaled tile only holds the vertical connectors of the pipes, which are
+    // never next to each other, so the first part of the loop on such a row is a crossing into it
+    // -> the cell right after it is INSIDE the loop, because we're starting from the edge
+    // (it's a tile corner, so it's never part of a pipe itself)
+    let inside_loop_pos = (0..matrix.len())
+        .step_by(3)
+        .find_map(|y| {
+            matrix[y]
+                .iter()
+                .position(|&n| n == loop_marker)
+                .map(|x| (x + 1, y))
+        })
+        .unwrap();
 
     fill(&mut matrix, inside_loop_pos, enclosed_marker, loop_marker);
 
@@ -240,75 +230,20 @@ fn part_2(input: &str) -> String {
     return enclosed_count.to_string();
 }
 
-fn main() {
-    let input = read_input("2023", "10");
-
-    let start_part_1 = Instant::now();
-    let part_1_result = part_1(&input);
-    let part_1_time = start_part_1.elapsed();
+pub struct Puzzle;
 
-    println!();
-    println!("Part 1: {} ({:?})", part_1_result, part_1_time);
-
-    let start_part_2 = Instant::now();
-    let part_2_result = part_2(&input);
-    let part_2_time = start_part_2.elapsed();
-
-    println!("Part 2: {} ({:?})", part_2_result, part_2_time);
-}
+impl Solution for Puzzle {
+    type Input = (Vec<Vec<u8>>, (usize, usize));
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_1() {
-        let input = r"
-..F7.
-.FJ|.
-SJ.L7
-|F--J
-LJ...
-        "
-        .trim();
-
-        assert_eq!(part_1(input), "8");
+    fn parse(input: &str) -> (Vec<Vec<u8>>, (usize, usize)) {
+        parse_input(input)
     }
 
-    #[test]
-    fn test_part_2_1() {
-        let input = r"
-...........
-.S-------7.
-.|F-----7|.
-.||.....||.
-.||.....||.
-.|L-7.F-J|.
-.|..|.|..|.
-.L--J.L--J.
-...........
-        "
-        .trim();
-
-        assert_eq!(part_2(input), "4");
+    fn part_1(input: &(Vec<Vec<u8>>, (usize, usize)), _: &Params) -> String {
+        part_1(input)
     }
 
-    #[test]
-    fn test_part_2_2() {
-        let input = r"
-.F----7F7F7F7F-7....
-.|F--7||||||||FJ....
-.||.FJ||||||||L7....
-FJL7L7LJLJ||LJ.L-7..
-L--J.L7...LJS7F-7L7.
-....F-J..F7FJ|L7L7L7
-....L7.F7||L7|.L7L7|
-.....|FJLJ|FJ|F7|.LJ
-....FJL-7.||.||||...
-....L---J.LJ.LJLJ...
-        "
-        .trim();
-
-        assert_eq!(part_2(input), "8");
+    fn part_2(input: &(Vec<Vec<u8>>, (usize, usize)), _: &Params) -> String {
+        part_2(input)
     }
 }
@@ -0,0 +1,106 @@
+use std::collections::HashSet;
+
+use crate::{
+    solution::{Param, Params, Solution},
+    uvec2::UVec2,
+};
+
+fn get_galaxies(input: &str, expansion_factor: usize) -> Vec<UVec2> {
+    let height = input.lines().count();
+    let width = input.lines().next().unwrap().chars().count();
+    let (mut galaxies, galaxy_xs, galaxy_ys) = input.lines().enumerate().fold(
+        (
+            Vec::<UVec2>::new(),
+            HashSet::<usize>::new(),
+            HashSet::<usize>::new(),
+        ),
+        |(mut vec, mut xs, mut ys), (y, line)| {
+            for (x, ch) in line.chars().enumerate() {
+                if ch == '#' {
+                    vec.push(UVec2::new(x, y));
+                    xs.insert(x);
+                    ys.insert(y);
+                }
+            }
+
+            (vec, xs, ys)
+        },
+    );
+
+    let x_gaps: Vec<usize> = (0..width)
+        .fold(
+            (Vec::<usize>::new(), 0),
+            |(mut gaps_vec, mut gaps_count), x| {
+                if !galaxy_xs.contains(&x) {
+                    gaps_count += 1;
+                }
+
+                gaps_vec.push(gaps_count);
+                (gaps_vec, gaps_count)
+            },
+        )
+        .0;
+    let y_gaps: Vec<usize> = (0..height)
+        .fold(
+            (Vec::<usize>::new(), 0),
+            |(mut gaps_vec, mut gaps_count), y| {
+                if !galaxy_ys.contains(&y) {
+                    gaps_count += 1;
+                }
+
+                gaps_vec.push(gaps_count);
+                (gaps_vec, gaps_count)
+            },
+        )
+        .0;
+
+    for galaxy in &mut galaxies {
+        *galaxy += UVec2::new(x_gaps[galaxy.x], y_gaps[galaxy.y]) * (expansion_factor - 1);
+    }
+
+    galaxies
+}
+
+fn get_sum_of_distances(galaxies: &Vec<UVec2>) -> usize {
+    let mut sum = 0;
+
+    for i in 0..galaxies.len() {
+        for j in i + 1..galaxies.len() {
+            sum += galaxies[i].manhattan(galaxies[j]);
+        }
+    }
+
+    sum
+}
+
+fn part_1(input: &str) -> String {
+    get_sum_of_distances(&get_galaxies(input, 2)).to_string()
+}
+
+fn part_2(input: &str, expansion_factor: usize) -> String {
+    get_sum_of_distances(&get_galaxies(input, expansion_factor)).to_string()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = String;
+
+    // the example expands by 10 and 100 instead
+    const PARAMS: &'static [Param] = &[Param {
+        name: "expansion",
+        default: "1000000",
+    }];
+
+    fn parse(input: &str) -> String {
+        input.to_string()
+    }
+
+    fn part_1(input: &String, _: &Params) -> String {
+        part_1(input)
+    }
+
+    fn part_2(input: &String, params: &Params) -> String {
+        part_2(input, params.get("expansion"))
+    }
+}
@@ -1,6 +1,6 @@
-use std::{collections::HashMap, time::Instant};
+use std::collections::HashMap;
 
-use aoc_rust::read_input;
+use crate::solution::{Params, Solution};
 use itertools::Itertools;
 
 fn parse_input(input: &str) -> (Vec<Vec<char>>, Vec<Vec<usize>>) {
@@ -226,42 +226,20 @@ fn part_2(input: &str) -> String {
         .to_string()
 }
 
-fn main() {
-    let input = read_input("2023", "12");
+pub struct Puzzle;
 
-    let start_part_1 = Instant::now();
-    let part_1_result = part_1(&input);
-    let part_1_time = start_part_1.elapsed();
+impl Solution for Puzzle {
+    type Input = String;
 
-    println!();
-    println!("Part 1: {} ({:?})", part_1_result, part_1_time);
-
-    let start_part_2 = Instant::now();
-    let part_2_result = part_2(&input);
-    let part_2_time = start_part_2.elapsed();
-
-    println!("Part 2: {} ({:?})", part_2_result, part_2_time);
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_1() {
-        let input = r"
-?###???????? 3,2,1
-        "
-        .trim();
-
-        assert_eq!(part_1(input), "21");
+    fn parse(input: &str) -> String {
+        input.to_string()
     }
 
-    #[ignore]
-    #[test]
-    fn test_part_2() {
-        let input = r"".trim();
+    fn part_1(input: &String, _: &Params) -> String {
+        part_1(input)
+    }
 
-        assert_eq!(part_2(input), "");
+    fn part_2(input: &String, _: &Params) -> String {
+        part_2(input)
     }
 }
@@ -1,6 +1,4 @@
-use std::time::Instant;
-
-use aoc_rust::read_input;
+use crate::solution::{Params, Solution};
 use itertools::Itertools;
 
 fn is_mirrored(slice: &[usize]) -> bool {
@@ -63,16 +61,13 @@ fn invert_char_at(matrix: &mut Vec<Vec<char>>, row: usize, col: usize) {
     matrix[row][col] = if matrix[row][col] == '#' { '.' } else { '#' };
 }
 
-fn get_scores(
-    mut matrix: &mut Vec<Vec<char>>,
-    expand_smudges: bool,
-) -> Vec<(Vec<usize>, Vec<usize>)> {
+fn get_scores(matrix: &mut Vec<Vec<char>>, expand_smudges: bool) -> Vec<(Vec<usize>, Vec<usize>)> {
     let row_scores = matrix
         .iter()
         .map(|row| {
             row.iter().enumerate().fold(0, |sum, (idx, &ch)| {
                 sum + if ch == '#' {
-                    (10 as usize).pow(idx as u32)
+                    10_usize.pow(idx as u32)
                 } else {
                     0
                 }
@@ -86,7 +81,7 @@ fn get_scores(
         let mut sum = 0;
         for row in 0..matrix.len() {
             sum += if matrix[row][col] == '#' {
-                (10 as usize).pow(row as u32)
+                10_usize.pow(row as u32)
             } else {
                 0
             }
@@ -108,9 +103,9 @@ fn get_scores(
                     //println!("Found col smudge: ({i}, {smudge_col})");
                     //println!("Found col smudge: ({j}, {smudge_col})");
 
-                    invert_char_at(&mut matrix, i, smudge_col);
+                    invert_char_at(matrix, i, smudge_col);
                     scores.append(&mut get_scores(matrix, false));
-                    invert_char_at(&mut matrix, i, smudge_col);
+                    invert_char_at(matrix, i, smudge_col);
                 }
             }
         }
@@ -129,12 +124,12 @@ fn get_scores(
                     //col_scores[i].abs_diff(col_scores[j])
                     //);
 
-                    invert_char_at(&mut matrix, smudge_row, i);
+                    invert_char_at(matrix, smudge_row, i);
                     scores.append(&mut get_scores(matrix, false));
-                    invert_char_at(&mut matrix, smudge_row, i);
-                    invert_char_at(&mut matrix, smudge_row, j);
+                    invert_char_at(matrix, smudge_row, i);
+                    invert_char_at(matrix, smudge_row, j);
                     scores.append(&mut get_scores(matrix, false));
-                    invert_char_at(&mut matrix, smudge_row, j);
+                    invert_char_at(matrix, smudge_row, j);
                 }
             }
         }
@@ -163,7 +158,7 @@ fn part_1(input: &str) -> String {
 
             let row_reflection_lines = get_reflection_lines(&scores[0].0);
 
-            if row_reflection_lines.len() > 0 {
+            if !row_reflection_lines.is_empty() {
                 //println!("Row reflection line: {}", row_reflection_lines[0]);
                 return (row_reflection_lines[0] + 1) * 100;
             }
@@ -208,8 +203,8 @@ fn part_2(input: &str) -> String {
                     //println!("row_reflection_lines:\n{:?}", row_reflection_lines);
                     //println!("col_reflection_lines:\n{:?}", col_reflection_lines);
 
-                    if row_reflection_lines.len() > 0 {
-                        if original_row_reflection_lines.len() == 0 {
+                    if !row_reflection_lines.is_empty() {
+                        if original_row_reflection_lines.is_empty() {
                             //println!(">> new row reflection line: {}", row_reflection_lines[0]);
                             return Some((row_reflection_lines[0] + 1) * 100);
                         }
@@ -218,17 +213,17 @@ fn part_2(input: &str) -> String {
                             .iter()
                             .find(|&line| *line != original_row_reflection_lines[0]);
 
-                        if new_row_reflection_line.is_some() {
+                        if let Some(new_row_reflection_line) = new_row_reflection_line {
                             //println!(
                             //">> new row reflection line: {}",
-                            //*new_row_reflection_line.unwrap()
+                            //*new_row_reflection_line
                             //);
-                            return Some((*new_row_reflection_line.unwrap() + 1) * 100);
+                            return Some((*new_row_reflection_line + 1) * 100);
                         }
                     }
 
-                    if col_reflection_lines.len() > 0 {
-                        if original_col_reflection_lines.len() == 0 {
+                    if !col_reflection_lines.is_empty() {
+                        if original_col_reflection_lines.is_empty() {
                             //println!(">> new col reflection line: {}", col_reflection_lines[0]);
                             return Some(col_reflection_lines[0] + 1);
                         }
@@ -237,12 +232,12 @@ fn part_2(input: &str) -> String {
                             .iter()
                             .find(|&line| *line != original_col_reflection_lines[0]);
 
-                        if new_col_reflection_line.is_some() {
+                        if let Some(new_col_reflection_line) = new_col_reflection_line {
                             //println!(
                             //">> new col reflection line: {}",
-                            //new_col_reflection_line.unwrap()
+                            //new_col_reflection_line
                             //);
-                            return Some(*new_col_reflection_line.unwrap() + 1);
+                            return Some(*new_col_reflection_line + 1);
                         }
                     }
 
@@ -254,72 +249,20 @@ fn part_2(input: &str) -> String {
         .to_string()
 }
 
-fn main() {
-    let input = read_input("2023", "13");
-
-    let start_part_1 = Instant::now();
-    let part_1_result = part_1(&input);
-    let part_1_time = start_part_1.elapsed();
-
-    println!();
-    println!("Part 1: {} ({:?})", part_1_result, part_1_time);
+pub struct Puzzle;
 
-    let start_part_2 = Instant::now();
-    let part_2_result = part_2(&input);
-    let part_2_time = start_part_2.elapsed();
+impl Solution for Puzzle {
+    type Input = String;
 
-    println!("Part 2: {} ({:?})", part_2_result, part_2_time);
-}
+    fn parse(input: &str) -> String {
+        input.to_string()
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_1() {
-        let input = r"
-#.##..##.
-..#.##.#.
-##......#
-##......#
-..#.##.#.
-..##..##.
-#.#.##.#.
-
-#...##..#
-#....#..#
-..##..###
-#####.##.
-#####.##.
-..##..###
-#....#..#
-        "
-        .trim();
-
-        assert_eq!(part_1(input), "405");
+    fn part_1(input: &String, _: &Params) -> String {
+        part_1(input)
     }
 
-    #[test]
-    fn test_part_2() {
-        let input = r"
-#.##..##.
-..#.##.#.
-##......#
-##......#
-..#.##.#.
-..##..##.
-#.#.##.#.
-
-#...##..#
-#....#..#
-..##..###
-#####.##.
-#####.##.
-..##..###
-#....#..#
-        "
-        .trim();
-
-        assert_eq!(part_2(input), "400");
+    fn part_2(input: &String, _: &Params) -> String {
+        part_2(input)
     }
 }
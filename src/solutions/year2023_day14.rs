@@ -1,6 +1,4 @@
-use std::time::Instant;
-
-use aoc_rust::read_input;
+use crate::solution::{Params, Solution};
 use itertools::Itertools;
 
 fn tilt_north(matrix: &mut Vec<Vec<char>>) {
@@ -146,71 +144,29 @@ fn part_2(input: &str) -> String {
         matrices.push(matrix);
     }
 
-    let mut matrix = matrices.last_mut().unwrap();
+    let matrix = matrices.last_mut().unwrap();
 
     for _ in 0..((1000000000 - cycles) % loop_size) {
-        cycle(&mut matrix);
+        cycle(matrix);
     }
 
-    get_total_load(&matrix).to_string()
+    get_total_load(matrix).to_string()
 }
 
-fn main() {
-    let input = read_input("2023", "14");
-
-    let start_part_1 = Instant::now();
-    let part_1_result = part_1(&input);
-    let part_1_time = start_part_1.elapsed();
+pub struct Puzzle;
 
-    println!();
-    println!("Part 1: {} ({:?})", part_1_result, part_1_time);
+impl Solution for Puzzle {
+    type Input = String;
 
-    let start_part_2 = Instant::now();
-    let part_2_result = part_2(&input);
-    let part_2_time = start_part_2.elapsed();
-
-    println!("Part 2: {} ({:?})", part_2_result, part_2_time);
-    //println!("Part 2 Brute Force: {}", part_2_bruteforce(&input));
-}
+    fn parse(input: &str) -> String {
+        input.to_string()
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_1() {
-        let input = r"
-O....#....
-O.OO#....#
-.....##...
-OO.#O....O
-.O.....O#.
-O.#..O.#.#
-..O..#O..O
-.......O..
-#....###..
-#OO..#...."
-            .trim();
-
-        assert_eq!(part_1(input), "136");
+    fn part_1(input: &String, _: &Params) -> String {
+        part_1(input)
     }
 
-    #[test]
-    fn test_part_2() {
-        let input = r"
-O....#....
-O.OO#....#
-.....##...
-OO.#O....O
-.O.....O#.
-O.#..O.#.#
-..O..#O..O
-.......O..
-#....###..
-#OO..#....
-        "
-        .trim();
-
-        assert_eq!(part_2(input), "64");
+    fn part_2(input: &String, _: &Params) -> String {
+        part_2(input)
     }
 }
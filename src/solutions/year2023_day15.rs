@@ -1,6 +1,4 @@
-use std::time::Instant;
-
-use aoc_rust::read_input;
+use crate::solution::{Params, Solution};
 
 fn hash_algo(s: &str) -> usize {
     s.chars()
@@ -34,15 +32,15 @@ fn part_2<'a>(input: &'a str) -> String {
                 let id = parts[0];
                 let focal_length = parts[1].parse::<usize>().unwrap();
 
-                if idx_of_lens.is_some() {
-                    bx[idx_of_lens.unwrap()].focal_length = focal_length;
+                if let Some(idx_of_lens) = idx_of_lens {
+                    bx[idx_of_lens].focal_length = focal_length;
                 } else {
                     bx.push(Lens { id, focal_length });
                 }
             } else {
                 // -
-                if idx_of_lens.is_some() {
-                    bx.remove(idx_of_lens.unwrap());
+                if let Some(idx_of_lens) = idx_of_lens {
+                    bx.remove(idx_of_lens);
                 }
             }
 
@@ -58,38 +56,20 @@ fn part_2<'a>(input: &'a str) -> String {
         .to_string()
 }
 
-fn main() {
-    let input = read_input("2023", "15");
-
-    let start_part_1 = Instant::now();
-    let part_1_result = part_1(&input);
-    let part_1_time = start_part_1.elapsed();
-
-    println!();
-    println!("Part 1: {} ({:?})", part_1_result, part_1_time);
-
-    let start_part_2 = Instant::now();
-    let part_2_result = part_2(&input);
-    let part_2_time = start_part_2.elapsed();
-
-    println!("Part 2: {} ({:?})", part_2_result, part_2_time);
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub struct Puzzle;
 
-    #[test]
-    fn test_part_1() {
-        let input = r"rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7".trim();
+impl Solution for Puzzle {
+    type Input = String;
 
-        assert_eq!(part_1(input), "1320");
+    fn parse(input: &str) -> String {
+        input.to_string()
     }
 
-    #[test]
-    fn test_part_2() {
-        let input = r"rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7".trim();
+    fn part_1(input: &String, _: &Params) -> String {
+        part_1(input)
+    }
 
-        assert_eq!(part_2(input), "145");
+    fn part_2(input: &String, _: &Params) -> String {
+        part_2(input)
     }
 }
@@ -0,0 +1,164 @@
+use crate::{
+    direction::{Direction, DirectionGrid},
+    ivec2::IVec2,
+    solution::{Params, Solution},
+    uvec2::UVec2,
+};
+use itertools::Itertools;
+
+fn parse_matrix(input: &str) -> Vec<Vec<char>> {
+    input
+        .lines()
+        .map(|line| line.chars().collect_vec())
+        .collect_vec()
+}
+
+fn beam(
+    matrix: &mut Vec<Vec<char>>,
+    pos: IVec2,
+    direction: Direction,
+    visited_with_direction: &mut DirectionGrid,
+) {
+    let Some(cell) = pos.in_bounds(UVec2::new(matrix[0].len(), matrix.len())) else {
+        return;
+    };
+
+    // already been here with the same direction, stop
+    if !visited_with_direction.insert(cell, direction) {
+        return;
+    }
+
+    let ch = matrix[cell.y][cell.x];
+
+    match ch {
+        '.' | '#' => beam(
+            matrix,
+            pos + direction.delta(),
+            direction,
+            visited_with_direction,
+        ),
+        '/' => {
+            let new_direction = match direction {
+                Direction::Left | Direction::Right => direction.turn_left(),
+                _ => direction.turn_right(),
+            };
+
+            beam(
+                matrix,
+                pos + new_direction.delta(),
+                new_direction,
+                visited_with_direction,
+            )
+        }
+        '\\' => {
+            let new_direction = match direction {
+                Direction::Left | Direction::Right => direction.turn_right(),
+                _ => direction.turn_left(),
+            };
+
+            beam(
+                matrix,
+                pos + new_direction.delta(),
+                new_direction,
+                visited_with_direction,
+            )
+        }
+        '|' => match direction {
+            Direction::Down | Direction::Up => beam(
+                matrix,
+                pos + direction.delta(),
+                direction,
+                visited_with_direction,
+            ),
+            _ => {
+                for split in [Direction::Down, Direction::Up] {
+                    beam(matrix, pos + split.delta(), split, visited_with_direction);
+                }
+            }
+        },
+        '-' => match direction {
+            Direction::Right | Direction::Left => beam(
+                matrix,
+                pos + direction.delta(),
+                direction,
+                visited_with_direction,
+            ),
+            _ => {
+                for split in [Direction::Right, Direction::Left] {
+                    beam(matrix, pos + split.delta(), split, visited_with_direction);
+                }
+            }
+        },
+        _ => panic!(),
+    }
+}
+
+// the number of energized tiles
+fn energize(
+    matrix: &mut Vec<Vec<char>>,
+    pos: IVec2,
+    direction: Direction,
+    visited_with_direction: &mut DirectionGrid,
+) -> usize {
+    visited_with_direction.clear();
+    beam(matrix, pos, direction, visited_with_direction);
+
+    visited_with_direction.visited()
+}
+
+fn part_1(matrix: &Vec<Vec<char>>) -> String {
+    let mut matrix = matrix.clone();
+    let mut visited_with_direction =
+        DirectionGrid::new(UVec2::new(matrix[0].len(), matrix.len()));
+
+    energize(
+        &mut matrix,
+        IVec2::ZERO,
+        Direction::Right,
+        &mut visited_with_direction,
+    )
+    .to_string()
+}
+
+fn part_2(matrix: &Vec<Vec<char>>) -> String {
+    let mut matrix = matrix.clone();
+    let width = matrix[0].len() as isize;
+    let height = matrix.len() as isize;
+    let mut visited_with_direction =
+        DirectionGrid::new(UVec2::new(matrix[0].len(), matrix.len()));
+
+    // from left, top, right and bottom
+    let starts = (0..height)
+        .map(|y| (IVec2::new(0, y), Direction::Right))
+        .chain((0..width).map(|x| (IVec2::new(x, 0), Direction::Down)))
+        .chain((0..height).map(|y| (IVec2::new(width - 1, y), Direction::Left)))
+        .chain((0..width).map(|x| (IVec2::new(x, height - 1), Direction::Up)))
+        .collect_vec();
+
+    starts
+        .into_iter()
+        .map(|(pos, direction)| {
+            energize(&mut matrix, pos, direction, &mut visited_with_direction)
+        })
+        .max()
+        .unwrap()
+        .to_string()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<Vec<char>>;
+
+    fn parse(input: &str) -> Vec<Vec<char>> {
+        parse_matrix(input)
+    }
+
+    fn part_1(input: &Vec<Vec<char>>, _: &Params) -> String {
+        part_1(input)
+    }
+
+    fn part_2(input: &Vec<Vec<char>>, _: &Params) -> String {
+        part_2(input)
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{
+    direction::Direction,
+    ivec2::IVec2,
+    solution::{Params, Solution},
+    uvec2::UVec2,
+};
+use itertools::Itertools;
+use priority_queue::PriorityQueue;
+
+fn solve(input: &str, skip: u8, max_forward: u8) -> String {
+    let matrix: Vec<Vec<u8>> = input
+        .lines()
+        .map(|line| line.chars().map(|ch| ch as u8 - b'0').collect_vec())
+        .collect_vec();
+    let size = UVec2::new(matrix[0].len(), matrix.len());
+
+    // cache for each pos and direction
+    let mut min_heat_losses: Vec<Vec<[usize; 4]>> =
+        vec![vec![[usize::MAX, usize::MAX, usize::MAX, usize::MAX]; matrix[0].len()]; matrix.len()];
+
+    // items are (pos, dir the node was reached in), cost is the total heat_loss * -1 (we
+    // prioritise lower losses)
+    // cba to impl a custom struct so -1 it is
+    let mut pq = PriorityQueue::<(IVec2, Direction), isize>::new();
+
+    pq.push((IVec2::ZERO, Direction::Down), 0);
+    pq.push((IVec2::ZERO, Direction::Right), 0);
+
+    while !pq.is_empty() {
+        let ((pos, direction), heat_loss) = pq.pop().unwrap();
+
+        if pos.x as usize == size.x - 1 && pos.y as usize == size.y - 1 {
+            return min_heat_losses[pos.y as usize][pos.x as usize]
+                .iter()
+                .min()
+                .unwrap()
+                .to_string();
+        }
+
+        // turn left or right from the direction the node was reached in
+        for new_dir in [direction.turn_left(), direction.turn_right()] {
+            // as we're directly adding multiple in one direction,
+            // we need to accumulate their heat_loss to enqueue with a correct prio
+            let mut new_loc_heat_loss_acc = 0;
+            // enqueue up to the max forward nodes in both directions
+            for i in 1..=max_forward {
+                let new_loc = pos + new_dir.delta() * i as isize;
+
+                let Some(UVec2 { x, y }) = new_loc.in_bounds(size) else {
+                    // if this one is not valid (out of matrix) then the rest won't be too
+                    break;
+                };
+
+                new_loc_heat_loss_acc += matrix[y][x] as isize;
+
+                if i < skip {
+                    continue;
+                }
+
+                let new_loc_heat_loss = -heat_loss + new_loc_heat_loss_acc;
+
+                if (new_loc_heat_loss as usize) <= min_heat_losses[y][x][new_dir.index()] {
+                    min_heat_losses[y][x][new_dir.index()] = new_loc_heat_loss as usize;
+
+                    pq.push((new_loc, new_dir), -new_loc_heat_loss);
+                }
+            }
+        }
+    }
+
+    unreachable!()
+}
+
+fn part_1(input: &str) -> String {
+    solve(input, 0, 3)
+}
+
+fn part_2(input: &str) -> String {
+    solve(input, 4, 10)
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = String;
+
+    fn parse(input: &str) -> String {
+        input.to_string()
+    }
+
+    fn part_1(input: &String, _: &Params) -> String {
+        part_1(input)
+    }
+
+    fn part_2(input: &String, _: &Params) -> String {
+        part_2(input)
+    }
+}
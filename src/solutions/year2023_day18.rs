@@ -0,0 +1,106 @@
+use crate::{
+    direction::Direction,
+    ivec2::IVec2,
+    solution::{Params, Solution},
+};
+use itertools::Itertools;
+
+#[derive(Debug)]
+struct Line {
+    pos: IVec2,
+    length: isize,
+    direction: Direction,
+}
+
+fn parse_lines(input: &str, hex: bool) -> (Vec<Line>, isize) {
+    let mut max_y = 0;
+
+    let lines = input
+        .lines()
+        .fold((IVec2::ZERO, Vec::<Line>::new()), |(pos, mut vec), line| {
+            let (direction_str, length_str, hex_str) =
+                line.split_whitespace().collect_tuple().unwrap();
+
+            let length = if hex {
+                isize::from_str_radix(&hex_str[2..7], 16).unwrap()
+            } else {
+                length_str.parse::<isize>().unwrap()
+            };
+
+            let direction = if hex {
+                Direction::try_from(hex_str.chars().nth(7).unwrap()).unwrap()
+            } else {
+                direction_str.parse::<Direction>().unwrap()
+            };
+
+            let new_pos = pos + direction.delta() * length;
+
+            max_y = max_y.max(new_pos.y);
+
+            vec.push(Line {
+                pos,
+                length,
+                direction,
+            });
+
+            (new_pos, vec)
+        })
+        .1;
+
+    (lines, max_y)
+}
+
+fn surface_area(lines: &Vec<Line>, max_y: isize) -> isize {
+    let mut surface_area = 0;
+
+    // https://www.mathsisfun.com/geometry/area-irregular-polygons.html
+    for line in lines {
+        let area = match line.direction {
+            Direction::Right => (max_y - line.pos.y + 1) * line.length,
+            Direction::Left => -(max_y - line.pos.y + 1) * line.length,
+            Direction::Down | Direction::Up => 0,
+            _ => unreachable!(),
+        };
+
+        surface_area += area;
+    }
+
+    // as our points represent the centre of 1x1x1 cubes we need to add additional surface area that we
+    // didn't calculate above
+    //
+    // this is done by using this formula that I couldn't figure out myself so I copied from reddit
+    // Annoying tbh
+    surface_area += lines.iter().map(|line| line.length).sum::<isize>() / 2 + 1;
+
+    surface_area
+}
+
+fn part_1(input: &str) -> String {
+    let (lines, max_y) = parse_lines(input, false);
+
+    surface_area(&lines, max_y).to_string()
+}
+
+fn part_2(input: &str) -> String {
+    let (lines, max_y) = parse_lines(input, true);
+
+    surface_area(&lines, max_y).to_string()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = String;
+
+    fn parse(input: &str) -> String {
+        input.to_string()
+    }
+
+    fn part_1(input: &String, _: &Params) -> String {
+        part_1(input)
+    }
+
+    fn part_2(input: &String, _: &Params) -> String {
+        part_2(input)
+    }
+}
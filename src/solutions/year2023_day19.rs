@@ -1,6 +1,6 @@
-use std::{collections::HashMap, time::Instant};
+use std::collections::HashMap;
 
-use aoc_rust::read_input;
+use crate::solution::{Params, Solution};
 use itertools::Itertools;
 
 #[derive(Debug)]
@@ -56,7 +56,7 @@ fn parse_workflows<'a>(workflows_str: &'a str) -> HashMap<&'a str, Workflow<'a>>
                                             _ => unreachable!(),
                                         },
                                         left: cond_str.chars().next().unwrap(),
-                                        right: *&cond_str[2..].parse::<usize>().unwrap(),
+                                        right: cond_str[2..].parse::<usize>().unwrap(),
                                     }),
                                     then,
                                 }
@@ -170,7 +170,7 @@ fn get_distinct_accepted_parts_count<'a>(
 ) -> usize {
     let nodes = &workflows.get(workflow_id).unwrap().nodes;
 
-    let mut workflow_ranges = ranges.clone();
+    let mut workflow_ranges = ranges;
 
     nodes
         .iter()
@@ -187,7 +187,7 @@ fn get_distinct_accepted_parts_count<'a>(
         //  if no condition ->
         //    handle "then" with the current workflow_ranges
         .map(|node| {
-            let mut matched_condition_ranges = workflow_ranges.clone();
+            let mut matched_condition_ranges = workflow_ranges;
 
             if let Some(condition) = &node.condition {
                 let matched_condition_range_to_change = match condition.left {
@@ -248,76 +248,20 @@ fn part_2(input: &str) -> String {
     .to_string()
 }
 
-fn main() {
-    let input = read_input("2023", "19");
+pub struct Puzzle;
 
-    let start_part_1 = Instant::now();
-    let part_1_result = part_1(&input);
-    let part_1_time = start_part_1.elapsed();
+impl Solution for Puzzle {
+    type Input = String;
 
-    println!();
-    println!("Part 1: {} ({:?})", part_1_result, part_1_time);
-
-    let start_part_2 = Instant::now();
-    let part_2_result = part_2(&input);
-    let part_2_time = start_part_2.elapsed();
-
-    println!("Part 2: {} ({:?})", part_2_result, part_2_time);
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_1() {
-        let input = r"
-px{a<2006:qkq,m>2090:A,rfg}
-pv{a>1716:R,A}
-lnx{m>1548:A,A}
-rfg{s<537:gd,x>2440:R,A}
-qs{s>3448:A,lnx}
-qkq{x<1416:A,crn}
-crn{x>2662:A,R}
-in{s<1351:px,qqz}
-qqz{s>2770:qs,m<1801:hdj,R}
-gd{a>3333:R,R}
-hdj{m>838:A,pv}
-
-{x=787,m=2655,a=1222,s=2876}
-{x=1679,m=44,a=2067,s=496}
-{x=2036,m=264,a=79,s=2244}
-{x=2461,m=1339,a=466,s=291}
-{x=2127,m=1623,a=2188,s=1013}
-        "
-        .trim();
-
-        assert_eq!(part_1(input), "19114");
+    fn parse(input: &str) -> String {
+        input.to_string()
     }
 
-    #[test]
-    fn test_part_2() {
-        let input = r"
-px{a<2006:qkq,m>2090:A,rfg}
-pv{a>1716:R,A}
-lnx{m>1548:A,A}
-rfg{s<537:gd,x>2440:R,A}
-qs{s>3448:A,lnx}
-qkq{x<1416:A,crn}
-crn{x>2662:A,R}
-in{s<1351:px,qqz}
-qqz{s>2770:qs,m<1801:hdj,R}
-gd{a>3333:R,R}
-hdj{m>838:A,pv}
-
-{x=787,m=2655,a=1222,s=2876}
-{x=1679,m=44,a=2067,s=496}
-{x=2036,m=264,a=79,s=2244}
-{x=2461,m=1339,a=466,s=291}
-{x=2127,m=1623,a=2188,s=1013}
-        "
-        .trim();
+    fn part_1(input: &String, _: &Params) -> String {
+        part_1(input)
+    }
 
-        assert_eq!(part_2(input), "167409079868000");
+    fn part_2(input: &String, _: &Params) -> String {
+        part_2(input)
     }
 }
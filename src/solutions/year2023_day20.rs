@@ -1,6 +1,6 @@
-use std::{collections::VecDeque, time::Instant};
+use std::collections::VecDeque;
 
-use aoc_rust::read_input;
+use crate::solution::{Params, Solution};
 use itertools::Itertools;
 
 const BROADCAST_MODULE_HASHED_ID: usize = 0;
@@ -48,8 +48,9 @@ fn hash_module_id(id: &str) -> usize {
     if id == "broadcaster" {
         BROADCAST_MODULE_HASHED_ID
     } else {
+        // bijective base 26, so ids of different lengths (e.g. "a" and "aa") can't collide
         id.chars().enumerate().fold(0, |sum, (idx, ch)| {
-            sum + (b'z').pow(idx as u32) as usize * (ch as u8 - b'a') as usize
+            sum + 27_usize.pow(idx as u32) * (ch as u8 - b'a' + 1) as usize
         })
     }
 }
@@ -60,13 +61,11 @@ fn parse_modules(input: &str) -> Vec<Module> {
         .map(|line| {
             let (type_and_id_str, _) = line.split_once(" -> ").unwrap();
 
-            let hashed = hash_module_id(if type_and_id_str == "broadcaster" {
+            hash_module_id(if type_and_id_str == "broadcaster" {
                 type_and_id_str
             } else {
                 &type_and_id_str[1..]
-            });
-
-            hashed
+            })
         })
         .max()
         .unwrap();
@@ -103,10 +102,10 @@ fn parse_modules(input: &str) -> Vec<Module> {
         };
 
         for output_id in outputs_str.split(", ") {
-            if let Some(module) = modules.get_mut(hash_module_id(output_id)) {
-                if let Module::Conjunction((_, state)) = module {
-                    state.push((hash_module_id(id), false))
-                };
+            if let Some(Module::Conjunction((_, state))) =
+                modules.get_mut(hash_module_id(output_id))
+            {
+                state.push((hash_module_id(id), false))
             }
         }
     }
@@ -153,7 +152,7 @@ fn part_2(input: &str) -> String {
         .enumerate()
         .find_map(|(idx, module)| {
             if let Module::Conjunction((outputs, _)) = module {
-                if outputs.iter().any(|&idx| idx == rx_hashed) {
+                if outputs.contains(&rx_hashed) {
                     Some(idx)
                 } else {
                     None
@@ -210,60 +209,20 @@ fn part_2(input: &str) -> String {
     }
 }
 
-fn main() {
-    let input = read_input("2023", "20");
-
-    let start_part_1 = Instant::now();
-    let part_1_result = part_1(&input);
-    let part_1_time = start_part_1.elapsed();
-
-    println!();
-    println!("Part 1: {} ({:?})", part_1_result, part_1_time);
+pub struct Puzzle;
 
-    let start_part_2 = Instant::now();
-    let part_2_result = part_2(&input);
-    let part_2_time = start_part_2.elapsed();
+impl Solution for Puzzle {
+    type Input = String;
 
-    println!("Part 2: {} ({:?})", part_2_result, part_2_time);
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_1_1() {
-        let input = r"
-broadcaster -> a, b, c
-%a -> b
-%b -> c
-%c -> inv
-&inv -> a
-        "
-        .trim();
-
-        assert_eq!(part_1(input), "32000000");
+    fn parse(input: &str) -> String {
+        input.to_string()
     }
 
-    #[test]
-    fn test_part_1_2() {
-        let input = r"
-broadcaster -> a
-%a -> inv, con
-&inv -> b
-%b -> con
-&con -> output
-        "
-        .trim();
-
-        assert_eq!(part_1(input), "11687500");
+    fn part_1(input: &String, _: &Params) -> String {
+        part_1(input)
     }
 
-    #[ignore]
-    #[test]
-    fn test_part_2() {
-        let input = r"".trim();
-
-        assert_eq!(part_2(input), "");
+    fn part_2(input: &String, _: &Params) -> String {
+        part_2(input)
     }
 }
@@ -0,0 +1,179 @@
+use std::collections::HashSet;
+
+use crate::{
+    ivec2::IVec2,
+    solution::{Param, Params, Solution},
+    uvec2::UVec2,
+};
+use itertools::Itertools;
+use priority_queue::PriorityQueue;
+
+const STEPS: [IVec2; 4] = [
+    IVec2::new(1, 0),
+    IVec2::new(0, 1),
+    IVec2::new(-1, 0),
+    IVec2::new(0, -1),
+];
+
+fn parse_matrix(input: &str) -> (Vec<Vec<char>>, UVec2) {
+    let mut matrix = input
+        .lines()
+        .map(|line| line.chars().collect_vec())
+        .collect_vec();
+
+    for row in 0..matrix.len() {
+        for col in 0..matrix[row].len() {
+            if matrix[row][col] == 'S' {
+                matrix[row][col] = '.';
+                return (matrix, UVec2::new(col, row));
+            }
+        }
+    }
+
+    unreachable!()
+}
+
+fn size(matrix: &Vec<Vec<char>>) -> UVec2 {
+    UVec2::new(matrix[0].len(), matrix.len())
+}
+
+fn can_go_to(pos: IVec2, matrix: &Vec<Vec<char>>, visited: &Vec<Vec<usize>>) -> Option<UVec2> {
+    pos.in_bounds(size(matrix))
+        .filter(|&UVec2 { x, y }| visited[y][x] == usize::MAX && matrix[y][x] != '#')
+}
+
+fn part_1((matrix, start): &(Vec<Vec<char>>, UVec2), target_steps: usize) -> String {
+    let mut visited = vec![vec![usize::MAX; matrix[0].len()]; matrix.len()];
+    let mut reachable = vec![vec![false; matrix[0].len()]; matrix.len()];
+
+    let mut pq = PriorityQueue::<UVec2, isize>::new();
+
+    pq.push(*start, 0);
+
+    while !pq.is_empty() {
+        let (pos, priority) = pq.pop().unwrap();
+        let step = -priority;
+
+        if step % 2 == (target_steps as isize) % 2 {
+            reachable[pos.y][pos.x] = true;
+        }
+
+        if step as usize == target_steps {
+            continue;
+        }
+
+        let pos = IVec2::try_from(pos).unwrap();
+
+        for next in STEPS.map(|step| pos + step) {
+            if let Some(next) = can_go_to(next, matrix, &visited) {
+                visited[next.y][next.x] = (step + 1) as usize;
+
+                pq.push(next, priority - 1);
+            }
+        }
+    }
+
+    reachable
+        .iter()
+        .map(|row| row.iter().map(|&r| if r { 1 } else { 0 }).sum::<usize>())
+        .sum::<usize>()
+        .to_string()
+}
+
+fn count_positions(map: &Vec<Vec<char>>, start: UVec2, steps: usize) -> usize {
+    let mut positions: HashSet<UVec2> = HashSet::new();
+    positions.insert(start);
+
+    for _ in 0..steps {
+        let mut new_positions: HashSet<UVec2> = HashSet::new();
+        for position in positions {
+            let position = IVec2::try_from(position).unwrap();
+
+            new_positions.extend(
+                STEPS
+                    .iter()
+                    .filter_map(|&step| (position + step).in_bounds(size(map)))
+                    .filter(|&UVec2 { x, y }| map[y][x] == '.'),
+            );
+        }
+        positions = new_positions;
+    }
+    positions.len()
+}
+
+// https://www.youtube.com/watch?v=9UOMZSL0JTg
+// so many assumptions based on input etc.. - did not like this at all, didn't bother after a certain point
+// and just followed the above video
+//
+// 0/10 task for me tbh
+fn part_2((matrix, start): &(Vec<Vec<char>>, UVec2)) -> String {
+    let start = *start;
+    let edge = matrix.len() - 1;
+
+    let map_size = matrix.len();
+    let grid_size = 26501365 / map_size - 1;
+
+    let even_maps_in_grid = (grid_size.div_ceil(2) * 2).pow(2);
+    let odd_maps_in_grid = (grid_size / 2 * 2 + 1).pow(2);
+
+    let odd_points_in_map = count_positions(matrix, start, map_size * 2 + 1);
+    let even_points_in_map = count_positions(matrix, start, map_size * 2);
+
+    let total_points_fully_in_grid =
+        odd_points_in_map * odd_maps_in_grid + even_points_in_map * even_maps_in_grid;
+
+    let corner_top = count_positions(matrix, UVec2::new(start.x, edge), map_size - 1);
+    let corner_right = count_positions(matrix, UVec2::new(0, start.y), map_size - 1);
+    let corner_bottom = count_positions(matrix, UVec2::new(start.x, 0), map_size - 1);
+    let corner_left = count_positions(matrix, UVec2::new(edge, start.y), map_size - 1);
+
+    let total_points_in_grid_corners = corner_top + corner_right + corner_bottom + corner_left;
+
+    let small_diag_top_right = count_positions(matrix, UVec2::new(0, edge), map_size / 2 - 1);
+    let small_diag_bottom_right = count_positions(matrix, UVec2::ZERO, map_size / 2 - 1);
+    let small_diag_bottom_left = count_positions(matrix, UVec2::new(edge, 0), map_size / 2 - 1);
+    let small_diag_top_left = count_positions(matrix, UVec2::new(edge, edge), map_size / 2 - 1);
+
+    let total_points_in_small_diags = (grid_size + 1)
+        * (small_diag_top_right
+            + small_diag_bottom_right
+            + small_diag_bottom_left
+            + small_diag_top_left);
+
+    let big_diag_top_right = count_positions(matrix, UVec2::new(0, edge), map_size * 3 / 2 - 1);
+    let big_diag_bottom_right = count_positions(matrix, UVec2::ZERO, map_size * 3 / 2 - 1);
+    let big_diag_bottom_left = count_positions(matrix, UVec2::new(edge, 0), map_size * 3 / 2 - 1);
+    let big_diag_top_left =
+        count_positions(matrix, UVec2::new(edge, edge), map_size * 3 / 2 - 1);
+
+    let total_points_in_big_diags = grid_size
+        * (big_diag_top_right + big_diag_bottom_right + big_diag_bottom_left + big_diag_top_left);
+
+    let total_points_in_diag = total_points_in_small_diags + total_points_in_big_diags;
+
+    (total_points_fully_in_grid + total_points_in_grid_corners + total_points_in_diag).to_string()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = (Vec<Vec<char>>, UVec2);
+
+    // the example only walks 6 steps
+    const PARAMS: &'static [Param] = &[Param {
+        name: "steps",
+        default: "64",
+    }];
+
+    fn parse(input: &str) -> (Vec<Vec<char>>, UVec2) {
+        parse_matrix(input)
+    }
+
+    fn part_1(input: &(Vec<Vec<char>>, UVec2), params: &Params) -> String {
+        part_1(input, params.get("steps"))
+    }
+
+    fn part_2(input: &(Vec<Vec<char>>, UVec2), _: &Params) -> String {
+        part_2(input)
+    }
+}
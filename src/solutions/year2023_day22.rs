@@ -1,52 +1,22 @@
-use std::time::Instant;
-
-use aoc_rust::read_input;
+use crate::{
+    solution::{Params, Solution},
+    vec3::{BoundingBox, Vec3},
+};
 use itertools::Itertools;
 use priority_queue::priority_queue;
 use priority_queue::PriorityQueue;
 
 #[derive(Debug, Clone)]
-struct Point3D {
-    x: usize,
-    y: usize,
-    z: usize,
-}
-
-#[derive(Debug, Clone)]
-struct Brick {
-    start: Point3D,
-    end: Point3D,
+pub struct Brick {
+    start: Vec3<usize>,
+    end: Vec3<usize>,
     supports: Vec<usize>,
     supported_by: Vec<usize>,
 }
 
-fn parse_point_3d(s: &str) -> Point3D {
-    let (x, y, z) = s
-        .split(",")
-        .map(|s| s.parse::<usize>().unwrap())
-        .collect_tuple()
-        .unwrap();
-
-    Point3D { x, y, z }
-}
-
-fn point_in_range(p: usize, range: (usize, usize)) -> bool {
-    p >= range.0 && p <= range.1
-}
-
 fn has_collision(brick_a: &Brick, brick_b: &Brick) -> bool {
-    (point_in_range(brick_a.start.z, (brick_b.start.z, brick_b.end.z))
-        || point_in_range(brick_a.end.z, (brick_b.start.z, brick_b.end.z))
-        || point_in_range(brick_b.start.z, (brick_a.start.z, brick_a.end.z))
-        || point_in_range(brick_b.end.z, (brick_a.start.z, brick_a.end.z)))
-        && (point_in_range(brick_a.start.x, (brick_b.start.x, brick_b.end.x))
-            || point_in_range(brick_a.end.x, (brick_b.start.x, brick_b.end.x))
-            || point_in_range(brick_b.start.x, (brick_a.start.x, brick_a.end.x))
-            || point_in_range(brick_b.end.x, (brick_a.start.x, brick_a.end.x)))
-        && (point_in_range(brick_a.start.y, (brick_b.start.y, brick_b.end.y))
-            || point_in_range(brick_a.end.y, (brick_b.start.y, brick_b.end.y))
-            || point_in_range(brick_b.start.y, (brick_a.start.y, brick_a.end.y))
-            || point_in_range(brick_b.end.y, (brick_a.start.y, brick_a.end.y)))
+    BoundingBox::new(brick_a.start, brick_a.end)
+        .intersects(&BoundingBox::new(brick_b.start, brick_b.end))
 }
 
 fn move_down(brick: &mut Brick) {
@@ -68,8 +38,8 @@ fn parse_bricks(input: &str) -> Vec<Brick> {
             let (start, end) = line.split_once("~").unwrap();
 
             Brick {
-                start: parse_point_3d(start),
-                end: parse_point_3d(end),
+                start: start.parse().unwrap(),
+                end: end.parse().unwrap(),
                 supports: vec![],
                 supported_by: vec![],
             }
@@ -126,8 +96,7 @@ fn parse_bricks(input: &str) -> Vec<Brick> {
         .0
 }
 
-fn part_1(input: &str) -> String {
-    let bricks = parse_bricks(input);
+fn part_1(bricks: &Vec<Brick>) -> String {
 
     bricks
         .iter()
@@ -140,8 +109,7 @@ fn part_1(input: &str) -> String {
         .to_string()
 }
 
-fn part_2(input: &str) -> String {
-    let bricks = parse_bricks(input);
+fn part_2(bricks: &Vec<Brick>) -> String {
 
     let mut sum = 0;
 
@@ -181,113 +149,20 @@ fn part_2(input: &str) -> String {
     sum.to_string()
 }
 
-fn main() {
-    let input = read_input("2023", "22");
-
-    let start_part_1 = Instant::now();
-    let part_1_result = part_1(&input);
-    let part_1_time = start_part_1.elapsed();
-
-    println!();
-    println!("Part 1: {} ({:?})", part_1_result, part_1_time);
-
-    let start_part_2 = Instant::now();
-    let part_2_result = part_2(&input);
-    let part_2_time = start_part_2.elapsed();
-
-    println!("Part 2: {} ({:?})", part_2_result, part_2_time);
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_1() {
-        let input = r"
-1,0,1~1,2,1
-0,2,3~2,2,3
-2,0,5~2,2,5
-0,0,4~0,2,4
-0,1,6~2,1,6
-0,0,2~2,0,2
-1,1,8~1,1,9
-        "
-        .trim();
-
-        assert_eq!(part_1(input), "5");
-    }
+pub struct Puzzle;
 
-    #[test]
-    fn test_part_1_1() {
-        let input = r"
-0,0,1~0,0,1
-0,0,3~0,0,3
-        "
-        .trim();
+impl Solution for Puzzle {
+    type Input = Vec<Brick>;
 
-        assert_eq!(part_1(input), "1");
+    fn parse(input: &str) -> Vec<Brick> {
+        parse_bricks(input)
     }
 
-    #[test]
-    fn test_part_1_2() {
-        let input = r"
-0,0,1~0,0,1
-0,1,3~0,1,3
-        "
-        .trim();
-
-        assert_eq!(part_1(input), "2");
-    }
-
-    #[test]
-    fn test_part_1_3() {
-        let input = r"
-0,0,1~0,0,10
-0,0,300~0,1,300
-        "
-        .trim();
-
-        assert_eq!(part_1(input), "1");
-    }
-
-    #[test]
-    fn test_part_1_4() {
-        let input = r"
-0,0,1~0,0,10
-0,0,300~0,1,300
-1,0,50~1,0,50
-        "
-        .trim();
-
-        assert_eq!(part_1(input), "2");
-    }
-
-    #[test]
-    fn test_part_1_5() {
-        let input = r"
-0,0,1~0,0,10
-0,1,1~0,1,10
-0,0,300~0,1,300
-        "
-        .trim();
-
-        assert_eq!(part_1(input), "3");
+    fn part_1(input: &Vec<Brick>, _: &Params) -> String {
+        part_1(input)
     }
 
-    #[test]
-    fn test_part_2() {
-        let input = r"
-1,0,1~1,2,1
-0,2,3~2,2,3
-2,0,5~2,2,5
-0,0,4~0,2,4
-0,1,6~2,1,6
-0,0,2~2,0,2
-1,1,8~1,1,9
-        "
-        .trim();
-
-        assert_eq!(part_2(input), "7");
+    fn part_2(input: &Vec<Brick>, _: &Params) -> String {
+        part_2(input)
     }
 }
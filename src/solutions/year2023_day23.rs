@@ -0,0 +1,253 @@
+use std::collections::HashSet;
+
+use crate::{
+    ivec2::IVec2,
+    solution::{Params, Solution},
+    uvec2::UVec2,
+};
+use itertools::Itertools;
+use priority_queue::PriorityQueue;
+
+const STEPS: [IVec2; 4] = [
+    IVec2::new(1, 0),
+    IVec2::new(-1, 0),
+    IVec2::new(0, 1),
+    IVec2::new(0, -1),
+];
+
+fn parse_matrix(input: &str) -> Vec<Vec<char>> {
+    input
+        .lines()
+        .map(|line| line.chars().collect_vec())
+        .collect_vec()
+}
+
+fn can_move_to(matrix: &Vec<Vec<char>>, visited: &Vec<Vec<bool>>, pos: IVec2) -> bool {
+    pos.in_bounds(UVec2::new(matrix[0].len(), matrix.len()))
+        .is_some_and(|UVec2 { x, y }| !visited[y][x] && matrix[y][x] != '#')
+}
+
+fn resolve_pos(
+    matrix: &Vec<Vec<char>>,
+    visited: &Vec<Vec<bool>>,
+    pos: IVec2,
+    steps: usize,
+    slippery_slopes: bool,
+) -> Option<(IVec2, usize)> {
+    if !can_move_to(matrix, visited, pos) {
+        return None;
+    }
+
+    let slope = match matrix[pos.y as usize][pos.x as usize] {
+        '>' => Some(IVec2::new(1, 0)),
+        'v' => Some(IVec2::new(0, 1)),
+        '^' => Some(IVec2::new(0, -1)),
+        '<' => Some(IVec2::new(-1, 0)),
+        _ => None,
+    };
+
+    match slope.filter(|_| slippery_slopes) {
+        Some(slope) => can_move_to(matrix, visited, pos + slope).then_some((pos + slope, steps + 2)),
+        None => Some((pos, steps + 1)),
+    }
+}
+
+fn part_1(matrix: &Vec<Vec<char>>) -> String {
+    let end = IVec2::new((matrix[0].len() - 2) as isize, (matrix.len() - 1) as isize);
+    let mut max_steps_at: Vec<Vec<usize>> = vec![vec![0; matrix[0].len()]; matrix.len()];
+
+    let mut pq = PriorityQueue::<(IVec2, usize, Vec<Vec<bool>>), isize>::new();
+
+    pq.push(
+        (
+            IVec2::new(1, 0),
+            0,
+            vec![vec![false; matrix[0].len()]; matrix.len()],
+        ),
+        0,
+    );
+
+    let mut max_steps = 0;
+
+    while !pq.is_empty() {
+        let ((pos, steps, mut visited), _) = pq.pop().unwrap();
+        let (x, y) = (pos.x as usize, pos.y as usize);
+
+        if max_steps_at[y][x] > steps {
+            continue;
+        }
+
+        max_steps_at[y][x] = steps;
+
+        if pos == end {
+            max_steps = max_steps.max(steps);
+        }
+
+        visited[y][x] = true;
+
+        let next = STEPS
+            .iter()
+            .filter_map(|&step| resolve_pos(matrix, &visited, pos + step, steps, true))
+            .collect_vec();
+
+        match next.len() {
+            0 => {}
+            1 => {
+                let (next_pos, next_steps) = next[0];
+
+                pq.push(
+                    (next_pos, next_steps, visited),
+                    (next_pos.manhattan(end) * next_steps) as isize,
+                );
+            }
+            _ => {
+                for &(next_pos, next_steps) in next.iter() {
+                    pq.push(
+                        (next_pos, next_steps, visited.clone()),
+                        (next_pos.manhattan(end) * next_steps) as isize,
+                    );
+                }
+            }
+        }
+        {}
+    }
+
+    max_steps.to_string()
+}
+
+fn part_2(matrix: &Vec<Vec<char>>) -> String {
+    let end = IVec2::new((matrix[0].len() - 2) as isize, (matrix.len() - 1) as isize);
+    let mut shortcuts: Vec<Vec<HashSet<(IVec2, usize)>>> =
+        vec![vec![HashSet::new(); matrix[0].len()]; matrix.len()];
+
+    let visited = vec![vec![false; matrix[0].len()]; matrix.len()];
+
+    for m_y in 0..matrix.len() {
+        for m_x in 0..matrix[m_y].len() {
+            if matrix[m_y][m_x] != '#' {
+                let m_pos = IVec2::new(m_x as isize, m_y as isize);
+                let connections = STEPS
+                    .iter()
+                    .map(|&step| m_pos + step)
+                    .filter(|&potential_next_pos| {
+                        can_move_to(matrix, &visited, potential_next_pos)
+                    })
+                    .collect_vec();
+
+                if (m_x == 1 && m_y == 0) || connections.len() > 2 {
+                    for &connection in connections.iter() {
+                        let mut pq: PriorityQueue<(IVec2, usize), usize> = PriorityQueue::new();
+                        let mut visited = vec![vec![false; matrix[0].len()]; matrix.len()];
+
+                        visited[m_y][m_x] = true;
+                        pq.push((connection, 1), 0);
+
+                        while !pq.is_empty() {
+                            let ((pos, steps), _) = pq.pop().unwrap();
+                            let (x, y) = (pos.x as usize, pos.y as usize);
+
+                            visited[y][x] = true;
+
+                            if pos == end {
+                                shortcuts[m_y][m_x].insert((end, steps));
+                                shortcuts[end.y as usize][end.x as usize].insert((m_pos, steps));
+
+                                continue;
+                            }
+
+                            let next = STEPS
+                                .iter()
+                                .map(|&step| pos + step)
+                                .filter(|&potential_next_pos| {
+                                    can_move_to(matrix, &visited, potential_next_pos)
+                                })
+                                .collect_vec();
+
+                            if next.is_empty() {
+                                continue;
+                            }
+
+                            if next.len() > 1 {
+                                shortcuts[m_y][m_x].insert((pos, steps));
+                                shortcuts[y][x].insert((m_pos, steps));
+                            } else {
+                                pq.push((next[0], steps + 1), 0);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let mut graph_intermediary_helper: Vec<UVec2> = vec![];
+
+    for y in 0..shortcuts.len() {
+        for x in 0..shortcuts[y].len() {
+            let paths = &shortcuts[y][x];
+
+            if !paths.is_empty() {
+                graph_intermediary_helper.push(UVec2::new(x, y));
+                //println!("Shortcuts starting from ({x}, {y}): {:?}", paths);
+            }
+        }
+    }
+
+    let mut graph: Vec<Vec<(usize, usize)>> = vec![vec![]; graph_intermediary_helper.len()];
+
+    for i in 0..graph_intermediary_helper.len() {
+        let UVec2 { x, y } = graph_intermediary_helper[i];
+
+        for &(pos, steps) in &shortcuts[y][x] {
+            let connection_idx = graph_intermediary_helper
+                .iter()
+                .find_position(|&&a| IVec2::try_from(a) == Ok(pos))
+                .unwrap()
+                .0;
+
+            graph[i].push((connection_idx, steps));
+        }
+    }
+
+    let mut max_steps = 0;
+    let target_idx = graph.len() - 1;
+
+    // (index, steps, visited)
+    // due to the small amount of nodes in the graph, we can store visited
+    // as a single u64 (usize) and manipulate bits to indicate which indices
+    // have been visited
+    let mut pq: Vec<(usize, usize, usize)> = vec![(0, 0, 0)];
+
+    while let Some((idx, steps, visited)) = pq.pop() {
+        if idx == target_idx {
+            max_steps = max_steps.max(steps);
+            continue;
+        }
+
+        for &(next_idx, next_steps) in &graph[idx] {
+            if visited & (1 << next_idx) == 0 {
+                pq.push((next_idx, steps + next_steps, visited | (1 << next_idx)));
+            }
+        }
+    }
+
+    max_steps.to_string()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<Vec<char>>;
+
+    fn parse(input: &str) -> Vec<Vec<char>> {
+        parse_matrix(input)
+    }
+
+    fn part_1(input: &Vec<Vec<char>>, _: &Params) -> String {
+        part_1(input)
+    }
+
+    fn part_2(input: &Vec<Vec<char>>, _: &Params) -> String {
+        part_2(input)
+    }
+}
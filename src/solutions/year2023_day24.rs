@@ -0,0 +1,138 @@
+use crate::{
+    solution::{Param, Params, Solution},
+    vec3::{BoundingBox, Rational, Vec3},
+};
+use itertools::Itertools;
+
+#[derive(Debug)]
+pub struct Hailstone {
+    position: Vec3<i64>,
+    velocity: Vec3<i64>,
+}
+
+fn parse_hailstones(input: &str) -> Vec<Hailstone> {
+    input
+        .lines()
+        .map(|line| {
+            let (position, velocity) = Vec3::parse_ray(line).unwrap();
+
+            Hailstone { position, velocity }
+        })
+        .collect_vec()
+}
+
+// x and y only
+fn flatten(v: Vec3<i64>) -> Vec3<i128> {
+    Vec3::new(v.x as i128, v.y as i128, 0)
+}
+
+fn part_1(hailstones: &Vec<Hailstone>, intersection_min: i64, intersection_max: i64) -> String {
+    let test_area = BoundingBox::new(
+        flatten(Vec3::new(intersection_min, intersection_min, 0)),
+        flatten(Vec3::new(intersection_max, intersection_max, 0)),
+    );
+    let mut intersections = 0;
+
+    for i in 0..hailstones.len() - 1 {
+        let (a, va) = (
+            flatten(hailstones[i].position),
+            flatten(hailstones[i].velocity),
+        );
+
+        for j in i + 1..hailstones.len() {
+            let (b, vb) = (
+                flatten(hailstones[j].position),
+                flatten(hailstones[j].velocity),
+            );
+
+            // a + t * va = b + s * vb
+            //
+            // crossing both sides with vb gets rid of s, and with va gets rid of t:
+            //
+            // t * (va x vb) = (b - a) x vb
+            // s * (va x vb) = (b - a) x va
+            //
+            // in 2D only the z of the cross products is left, and it's 0 for parallel paths
+            let denominator = va.cross(vb).z;
+
+            if denominator == 0 {
+                continue;
+            }
+
+            // t and s as fractions over a positive denominator, which everything else is
+            // multiplied by to stay exact
+            let sign = denominator.signum();
+            let (t, s) = ((b - a).cross(vb).z * sign, (b - a).cross(va).z * sign);
+            let denominator = denominator * sign;
+
+            if t > 0
+                && s > 0
+                && BoundingBox::new(test_area.min * denominator, test_area.max * denominator)
+                    .contains(a * denominator + va * t)
+            {
+                intersections += 1;
+            }
+        }
+    }
+    return intersections.to_string();
+}
+
+fn rational(v: Vec3<i64>) -> Vec3<Rational> {
+    v.map(|coord| Rational::from(coord as i128))
+}
+
+fn part_2(hailstones: &Vec<Hailstone>) -> String {
+    // seen from the first hailstone, which then stands still at the origin, the rock goes
+    // through the origin, so it lies in the plane of the origin and any other hailstone's path
+    let origin = &hailstones[0];
+    let relative = |h: &Hailstone| {
+        (
+            rational(h.position - origin.position),
+            rational(h.velocity - origin.velocity),
+        )
+    };
+    let (p1, v1) = relative(&hailstones[1]);
+    let (p2, v2) = relative(&hailstones[2]);
+    let (n1, n2) = (p1.cross(v1), p2.cross(v2));
+
+    // each of the two hailstones is hit where its path crosses the other one's plane
+    let t1 = -p1.dot(n2) / v1.dot(n2);
+    let t2 = -p2.dot(n1) / v2.dot(n1);
+    let hit = |h: &Hailstone, t| rational(h.position) + rational(h.velocity) * t;
+    let (hit_1, hit_2) = (hit(&hailstones[1], t1), hit(&hailstones[2], t2));
+
+    let velocity = (hit_2 - hit_1) * (Rational::from(1) / (t2 - t1));
+    let position = hit_1 - velocity * t1;
+
+    (position.x + position.y + position.z).to_string()
+}
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Input = Vec<Hailstone>;
+
+    // the test area, 7 to 27 in the example
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "min",
+            default: "200000000000000",
+        },
+        Param {
+            name: "max",
+            default: "400000000000000",
+        },
+    ];
+
+    fn parse(input: &str) -> Vec<Hailstone> {
+        parse_hailstones(input)
+    }
+
+    fn part_1(input: &Vec<Hailstone>, params: &Params) -> String {
+        part_1(input, params.get("min"), params.get("max"))
+    }
+
+    fn part_2(input: &Vec<Hailstone>, _: &Params) -> String {
+        part_2(input)
+    }
+}
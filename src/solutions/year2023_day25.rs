@@ -1,9 +1,6 @@
-use std::{
-    collections::{HashMap, HashSet},
-    time::Instant,
-};
+use std::collections::{HashMap, HashSet};
 
-use aoc_rust::read_input;
+use crate::solution::{Params, Solution};
 use itertools::Itertools;
 
 use rand::Rng;
@@ -15,20 +12,20 @@ struct SuperVertex<'a> {
 }
 
 fn contract<'a>(sv: &mut SuperVertex<'a>, other: &mut SuperVertex<'a>) {
-    for (_, conns) in &mut sv.edges {
+    for conns in sv.edges.values_mut() {
         for &v in &other.vertices {
             conns.remove(v);
         }
     }
 
-    for (_, conns) in &mut other.edges {
+    for conns in other.edges.values_mut() {
         for &v in &sv.vertices {
             conns.remove(v);
         }
     }
 
     for (v, conns) in &mut other.edges {
-        if sv.edges.get(v).is_none() {
+        if !sv.edges.contains_key(v) {
             sv.edges.insert(v, conns.clone());
         } else {
             for &vv in conns.iter() {
@@ -48,7 +45,7 @@ fn parse_graph<'a>(input: &'a str) -> Vec<SuperVertex<'a>> {
             |mut graph, line| {
                 let (left, right) = line.split_once(": ").unwrap();
 
-                if graph.get(left).is_none() {
+                if !graph.contains_key(left) {
                     graph.insert(
                         left,
                         SuperVertex {
@@ -73,7 +70,7 @@ fn parse_graph<'a>(input: &'a str) -> Vec<SuperVertex<'a>> {
                         .unwrap()
                         .insert(r);
 
-                    if graph.get(r).is_none() {
+                    if !graph.contains_key(r) {
                         graph.insert(
                             r,
                             SuperVertex {
@@ -118,7 +115,7 @@ fn part_1(input: &str) -> String {
 
             let mut v: &str = "";
 
-            while sv.edges.get(v).is_none() || sv.edges.get(v).unwrap().is_empty() {
+            while !sv.edges.contains_key(v) || sv.edges.get(v).unwrap().is_empty() {
                 v = sv.vertices[rng.gen_range(0..sv.vertices.len())];
             }
 
@@ -130,59 +127,35 @@ fn part_1(input: &str) -> String {
                 .nth(rng.gen_range(0..sv.edges.get(v).unwrap().len()))
                 .unwrap();
 
-            let mut target_sv = g
+            let target_sv = g
                 .iter_mut()
-                .find(|sv| sv.vertices.iter().any(|&v| v == other_v))
+                .find(|sv| sv.vertices.contains(&other_v))
                 .unwrap();
 
-            contract(&mut target_sv, &mut sv);
+            contract(target_sv, &mut sv);
         }
-        if g[0]
-            .edges
-            .iter()
-            .map(|(_, conns)| conns.len())
-            .sum::<usize>()
-            == 3
-        {
+        if g[0].edges.values().map(|conns| conns.len()).sum::<usize>() == 3 {
             return (g[0].vertices.len() * g[1].vertices.len()).to_string();
         }
     }
 }
 
-fn main() {
-    let input = read_input("2023", "25");
+pub struct Puzzle;
 
-    let start_part_1 = Instant::now();
-    let part_1_result = part_1(&input);
-    let part_1_time = start_part_1.elapsed();
+impl Solution for Puzzle {
+    type Input = String;
 
-    println!();
-    println!("Part 1: {} ({:?})", part_1_result, part_1_time);
-}
+    const HAS_PART_2: bool = false;
+
+    fn parse(input: &str) -> String {
+        input.to_string()
+    }
+
+    fn part_1(input: &String, _: &Params) -> String {
+        part_1(input)
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_1() {
-        let input = r"
-jqt: rhn xhk nvd
-rsh: frs pzl lsr
-xhk: hfx
-cmg: qnr nvd lhk bvb
-rhn: xhk bvb hfx
-bvb: xhk hfx
-pzl: lsr hfx nvd
-qnr: nvd
-ntq: jqt hfx bvb xhk
-nvd: lhk
-lsr: lhk
-rzs: qnr cmg lsr rsh
-frs: qnr lhk lsr
-        "
-        .trim();
-
-        assert_eq!(part_1(input), "54");
+    fn part_2(_input: &String, _: &Params) -> String {
+        String::new()
     }
 }
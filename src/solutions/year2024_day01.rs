@@ -1,6 +1,6 @@
-use std::{collections::HashMap, time::Instant};
+use std::collections::HashMap;
 
-use aoc_rust::read_input;
+use crate::solution::{Params, Solution};
 use itertools::Itertools;
 
 fn parse_lists(input: &str) -> (Vec<usize>, Vec<usize>) {
@@ -47,55 +47,20 @@ fn part_2(input: &str) -> String {
         .to_string();
 }
 
-fn main() {
-    let input = read_input("2024", "01");
+pub struct Puzzle;
 
-    let start_part_1 = Instant::now();
-    let part_1_result = part_1(&input);
-    let part_1_time = start_part_1.elapsed();
+impl Solution for Puzzle {
+    type Input = String;
 
-    println!();
-    println!("Part 1: {} ({:?})", part_1_result, part_1_time);
-
-    let start_part_2 = Instant::now();
-    let part_2_result = part_2(&input);
-    let part_2_time = start_part_2.elapsed();
-
-    println!("Part 2: {} ({:?})", part_2_result, part_2_time);
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_1() {
-        let input = r"
-3   4
-4   3
-2   5
-1   3
-3   9
-3   3
-            "
-        .trim();
-
-        assert_eq!(part_1(input), "11");
+    fn parse(input: &str) -> String {
+        input.to_string()
     }
 
-    #[test]
-    fn test_part_2() {
-        let input = r"
-3   4
-4   3
-2   5
-1   3
-3   9
-3   3
-            "
-        .trim();
+    fn part_1(input: &String, _: &Params) -> String {
+        part_1(input)
+    }
 
-        assert_eq!(part_2(input), "31");
+    fn part_2(input: &String, _: &Params) -> String {
+        part_2(input)
     }
 }
-
@@ -1,6 +1,4 @@
-use std::time::Instant;
-
-use aoc_rust::read_input;
+use crate::solution::{Params, Solution};
 use itertools::Itertools;
 
 fn is_safe(report: &Vec<usize>, skip_index: Option<usize>) -> bool {
@@ -70,7 +68,7 @@ fn part_2(input: &str) -> String {
                 .collect_vec();
 
             let is_any_safe = std::iter::once(None)
-                .chain((0..xs.len()).map(|i| Some(i)))
+                .chain((0..xs.len()).map(Some))
                 .any(|skip_index| is_safe(&xs, skip_index));
 
             if is_any_safe {
@@ -83,54 +81,20 @@ fn part_2(input: &str) -> String {
         .to_string();
 }
 
-fn main() {
-    let input = read_input("2024", "02");
-
-    let start_part_1 = Instant::now();
-    let part_1_result = part_1(&input);
-    let part_1_time = start_part_1.elapsed();
-
-    println!();
-    println!("Part 1: {} ({:?})", part_1_result, part_1_time);
+pub struct Puzzle;
 
-    let start_part_2 = Instant::now();
-    let part_2_result = part_2(&input);
-    let part_2_time = start_part_2.elapsed();
+impl Solution for Puzzle {
+    type Input = String;
 
-    println!("Part 2: {} ({:?})", part_2_result, part_2_time);
-}
+    fn parse(input: &str) -> String {
+        input.to_string()
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_1() {
-        let input = r"
-7 6 4 2 1
-1 2 7 8 9
-9 7 6 2 1
-1 3 2 4 5
-8 6 4 4 1
-1 3 6 7 9
-            "
-        .trim();
-
-        assert_eq!(part_1(input), "2");
+    fn part_1(input: &String, _: &Params) -> String {
+        part_1(input)
     }
 
-    #[test]
-    fn test_part_2() {
-        let input = r"
-7 6 4 2 1
-1 2 7 8 9
-9 7 6 2 1
-1 3 2 4 5
-8 6 4 4 1
-1 3 6 7 9
-            "
-        .trim();
-
-        assert_eq!(part_2(input), "4");
+    fn part_2(input: &String, _: &Params) -> String {
+        part_2(input)
     }
 }
@@ -1,6 +1,4 @@
-use std::time::Instant;
-
-use aoc_rust::read_input;
+use crate::solution::{Params, Solution};
 use nom::{
     branch::alt,
     bytes::complete::{tag, take_until},
@@ -22,7 +20,7 @@ fn parse_expression(input: &str) -> IResult<&str, (usize, usize)> {
 fn part_1(input: &str) -> String {
     let res = fold_many0(
         (take_until("mul"), cut(tag("mul")), opt(parse_expression)),
-        || 0 as usize,
+        || 0_usize,
         |acc, (_, _, pair)| match pair {
             Some((a, b)) => acc + a * b,
             None => acc,
@@ -82,21 +80,22 @@ fn part_2(input: &str) -> String {
     return res.to_string();
 }
 
-fn main() {
-    let input = read_input("2024", "03");
+pub struct Puzzle;
 
-    let start_part_1 = Instant::now();
-    let part_1_result = part_1(&input);
-    let part_1_time = start_part_1.elapsed();
+impl Solution for Puzzle {
+    type Input = String;
 
-    println!();
-    println!("Part 1: {} ({:?})", part_1_result, part_1_time);
+    fn parse(input: &str) -> String {
+        input.to_string()
+    }
 
-    let start_part_2 = Instant::now();
-    let part_2_result = part_2(&input);
-    let part_2_time = start_part_2.elapsed();
+    fn part_1(input: &String, _: &Params) -> String {
+        part_1(input)
+    }
 
-    println!("Part 2: {} ({:?})", part_2_result, part_2_time);
+    fn part_2(input: &String, _: &Params) -> String {
+        part_2(input)
+    }
 }
 
 #[cfg(test)]
@@ -113,24 +112,4 @@ mod tests {
         assert_eq!(parse_expression("(23,45)"), Ok(("", (23, 45))));
         assert_eq!(parse_expression("(999,1)"), Ok(("", (999, 1))));
     }
-
-    #[test]
-    fn test_part_1() {
-        let input = r"
-xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))
-            "
-        .trim();
-
-        assert_eq!(part_1(input), "161");
-    }
-
-    #[test]
-    fn test_part_2() {
-        let input = r"
-xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))
-            "
-        .trim();
-
-        assert_eq!(part_2(input), "48");
-    }
 }
@@ -1,11 +1,9 @@
-use std::time::Instant;
-
-use aoc_rust::{direction::Direction, read_input, traversable_matrix::TraversableMatrix};
+use crate::{direction::Direction, solution::{Params, Solution}, traversable_matrix::TraversableMatrix};
 
 const TARGET_LETTERS: &[char] = &['X', 'M', 'A', 'S'];
 
-fn part_1(input: &str) -> String {
-    let mut m = TraversableMatrix::<char>::from_str(input);
+fn part_1(matrix: &TraversableMatrix<char>) -> String {
+    let mut m = matrix.clone();
     let mut xmas_count = 0;
 
     for y in 0..m.height {
@@ -37,8 +35,8 @@ fn part_1(input: &str) -> String {
     return xmas_count.to_string();
 }
 
-fn part_2(input: &str) -> String {
-    let mut m = TraversableMatrix::<char>::from_str(input);
+fn part_2(matrix: &TraversableMatrix<char>) -> String {
+    let mut m = matrix.clone();
     let mut x_mas_count = 0;
 
     for y in 0..m.height {
@@ -66,62 +64,20 @@ fn part_2(input: &str) -> String {
     return x_mas_count.to_string();
 }
 
-fn main() {
-    let input = read_input("2024", "04");
-
-    let start_part_1 = Instant::now();
-    let part_1_result = part_1(&input);
-    let part_1_time = start_part_1.elapsed();
-
-    println!();
-    println!("Part 1: {} ({:?})", part_1_result, part_1_time);
+pub struct Puzzle;
 
-    let start_part_2 = Instant::now();
-    let part_2_result = part_2(&input);
-    let part_2_time = start_part_2.elapsed();
+impl Solution for Puzzle {
+    type Input = TraversableMatrix<char>;
 
-    println!("Part 2: {} ({:?})", part_2_result, part_2_time);
-}
+    fn parse(input: &str) -> TraversableMatrix<char> {
+        TraversableMatrix::from_str(input)
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_1() {
-        let input = r"
-MMMSXXMASM
-MSAMXMSMSA
-AMXSXMAAMM
-MSAMASMSMX
-XMASAMXAMM
-XXAMMXXAMA
-SMSMSASXSS
-SAXAMASAAA
-MAMMMXMMMM
-MXMXAXMASX
-            "
-        .trim();
-
-        assert_eq!(part_1(input), "18");
+    fn part_1(input: &TraversableMatrix<char>, _: &Params) -> String {
+        part_1(input)
     }
 
-    #[test]
-    fn test_part_2() {
-        let input = r"
-MMMSXXMASM
-MSAMXMSMSA
-AMXSXMAAMM
-MSAMASMSMX
-XMASAMXAMM
-XXAMMXXAMA
-SMSMSASXSS
-SAXAMASAAA
-MAMMMXMMMM
-MXMXAXMASX
-        "
-        .trim();
-
-        assert_eq!(part_2(input), "9");
+    fn part_2(input: &TraversableMatrix<char>, _: &Params) -> String {
+        part_2(input)
     }
 }
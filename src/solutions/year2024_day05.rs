@@ -1,6 +1,6 @@
-use std::{collections::HashMap, time::Instant};
+use std::collections::HashMap;
 
-use aoc_rust::read_input;
+use crate::solution::{Params, Solution};
 use nom::{
     bytes::complete::tag,
     character::complete::{newline, usize},
@@ -73,7 +73,7 @@ fn part_2(input: &str) -> String {
             let mut did_fix_update = false;
 
             for &n in update {
-                if forbidden_ns.iter().any(|&x| x == n) {
+                if forbidden_ns.contains(&n) {
                     did_fix_update = true;
 
                     // if forbidden, look for the first entry in the fixed_update
@@ -107,21 +107,22 @@ fn part_2(input: &str) -> String {
         .to_string()
 }
 
-fn main() {
-    let input = read_input("2024", "05");
+pub struct Puzzle;
 
-    let start_part_1 = Instant::now();
-    let part_1_result = part_1(&input);
-    let part_1_time = start_part_1.elapsed();
+impl Solution for Puzzle {
+    type Input = String;
 
-    println!();
-    println!("Part 1: {} ({:?})", part_1_result, part_1_time);
+    fn parse(input: &str) -> String {
+        input.to_string()
+    }
 
-    let start_part_2 = Instant::now();
-    let part_2_result = part_2(&input);
-    let part_2_time = start_part_2.elapsed();
+    fn part_1(input: &String, _: &Params) -> String {
+        part_1(input)
+    }
 
-    println!("Part 2: {} ({:?})", part_2_result, part_2_time);
+    fn part_2(input: &String, _: &Params) -> String {
+        part_2(input)
+    }
 }
 
 #[cfg(test)]
@@ -130,7 +131,7 @@ mod tests {
 
     #[test]
     fn test_parse_rule() {
-        assert_eq!(parse_rule("420|69"), Ok(("", ((420, 69)))));
+        assert_eq!(parse_rule("420|69"), Ok(("", (420, 69))));
     }
 
     #[test]
@@ -140,79 +141,4 @@ mod tests {
             Ok(("", vec![vec![1, 2, 3], vec![69, 420]]))
         );
     }
-
-    #[test]
-    fn test_part_1() {
-        let input = r"
-47|53
-97|13
-97|61
-97|47
-75|29
-61|13
-75|53
-29|13
-97|29
-53|29
-61|53
-97|53
-61|29
-47|13
-75|47
-97|75
-47|61
-75|61
-47|29
-75|13
-53|13
-
-75,47,61,53,29
-97,61,53,29,13
-75,29,13
-75,97,47,61,53
-61,13,29
-97,13,75,29,47
-            "
-        .trim();
-
-        assert_eq!(part_1(input), "143");
-    }
-
-    #[test]
-    fn test_part_2() {
-        let input = r"
-47|53
-97|13
-97|61
-97|47
-75|29
-61|13
-75|53
-29|13
-97|29
-53|29
-61|53
-97|53
-61|29
-47|13
-75|47
-97|75
-47|61
-75|61
-47|29
-75|13
-53|13
-
-75,47,61,53,29
-97,61,53,29,13
-75,29,13
-75,97,47,61,53
-61,13,29
-97,13,75,29,47
-            "
-        .trim();
-
-        assert_eq!(part_2(input), "123");
-    }
 }
-
@@ -1,18 +1,15 @@
-use std::{panic, time::Instant};
+use std::panic;
 
-use aoc_rust::{
-    direction::Direction, read_input, traversable_matrix::TraversableMatrix, uvec2::UVec2,
-};
-use rayon::{
-    iter::{IntoParallelRefIterator, ParallelIterator},
-    slice::ParallelSlice,
+use crate::{
+    direction::{Direction, DirectionSet}, solution::{Params, Solution}, traversable_matrix::TraversableMatrix, uvec2::UVec2,
 };
+use rayon::{iter::ParallelIterator, slice::ParallelSlice};
 
 fn get_starting_position(m: &mut TraversableMatrix<char>) -> UVec2 {
     for y in 0..m.height {
         for x in 0..m.width {
             if m.matrix[y][x] == '^' {
-                return UVec2 { x, y };
+                return UVec2::new(x, y);
             }
         }
     }
@@ -20,16 +17,6 @@ fn get_starting_position(m: &mut TraversableMatrix<char>) -> UVec2 {
     panic!("Could not find starting position");
 }
 
-fn get_rotated_direction(direction: Direction) -> Direction {
-    match direction {
-        Direction::Up => Direction::Right,
-        Direction::Right => Direction::Down,
-        Direction::Down => Direction::Left,
-        Direction::Left => Direction::Up,
-        _ => Direction::Up,
-    }
-}
-
 fn get_distinct_visited_positions(m: &mut TraversableMatrix<char>) -> Vec<UVec2> {
     let mut direction = Direction::Up;
     let mut distinct_positions: Vec<UVec2> = vec![];
@@ -42,17 +29,14 @@ fn get_distinct_visited_positions(m: &mut TraversableMatrix<char>) -> Vec<UVec2>
 
     while let Some(ch) = m.peek_in_dir(direction) {
         if ch == '#' {
-            direction = get_rotated_direction(direction);
+            direction = direction.turn_right();
             continue;
         }
 
         m.move_in_dir(direction);
 
         if ch == '.' {
-            distinct_positions.push(UVec2 {
-                x: m.position.x,
-                y: m.position.y,
-            });
+            distinct_positions.push(m.position);
         }
 
         m.matrix[m.position.y][m.position.x] = 'X';
@@ -61,8 +45,8 @@ fn get_distinct_visited_positions(m: &mut TraversableMatrix<char>) -> Vec<UVec2>
     return distinct_positions;
 }
 
-fn part_1(input: &str) -> String {
-    let mut m = TraversableMatrix::<char>::from_str(input);
+fn part_1(matrix: &TraversableMatrix<char>) -> String {
+    let mut m = matrix.clone();
 
     // add 1 to include the starting position
     (get_distinct_visited_positions(&mut m).len() + 1).to_string()
@@ -71,8 +55,8 @@ fn part_1(input: &str) -> String {
 #[derive(Clone, Copy, Debug)]
 struct Obstacle {
     position: UVec2,
-    // bit is raised for each direction that the guard moved in before colliding with the obstacle
-    collision_directions: u8,
+    // the directions that the guard moved in before colliding with the obstacle
+    collision_directions: DirectionSet,
 }
 
 struct MatrixObstacles {
@@ -89,8 +73,8 @@ impl MatrixObstacles {
         let mut obstacles = MatrixObstacles {
             all: vec![
                 Obstacle {
-                    position: UVec2 { x: 0, y: 0 },
-                    collision_directions: 0
+                    position: UVec2::ZERO,
+                    collision_directions: DirectionSet::EMPTY
                 };
                 m.width * m.height
             ],
@@ -174,18 +158,16 @@ impl MatrixObstacles {
         let mut direction = starting_direction;
 
         while let Some(index) = self.find_obstacle(pos, direction) {
-            let bitmask = 2 << direction as u8;
             let obstacle = &mut self.all[index];
 
             //println!("collided while moving {:?} with {:?}", direction, obstacle);
 
-            // if obstacle was already collided from this direction, we have a loop
-            if obstacle.collision_directions & bitmask > 0 {
+            // remember that we collided with this obstacle from this direction, if we already
+            // had, we have a loop
+            if !obstacle.collision_directions.insert(direction) {
                 return true;
             }
 
-            // remember that we collided with this obstacle from this direction
-            obstacle.collision_directions |= bitmask;
             self.visited_indices.push(index);
 
             // we set the position of the guard in relation to the collided obstacle
@@ -203,7 +185,7 @@ impl MatrixObstacles {
                 Direction::Down => obstacle.position.y - 1,
                 _ => pos.y,
             };
-            direction = get_rotated_direction(direction);
+            direction = direction.turn_right();
 
             //println!("searching from pos {:?} and dir {:?}", pos, direction);
         }
@@ -216,8 +198,7 @@ impl MatrixObstacles {
     fn insert_obstacle(&mut self, x: usize, y: usize) {
         let index = self.get_1d_index(x, y);
 
-        self.all[index].position.x = x;
-        self.all[index].position.y = y;
+        self.all[index].position = UVec2::new(x, y);
         self.by_y[y].push(x);
         self.by_y[y].sort();
         self.by_x[x].push(y);
@@ -227,9 +208,8 @@ impl MatrixObstacles {
     fn remove_obstacle(&mut self, x: usize, y: usize) {
         let index = self.get_1d_index(x, y);
 
-        self.all[index].position.x = 0;
-        self.all[index].position.y = 0;
-        self.all[index].collision_directions = 0;
+        self.all[index].position = UVec2::ZERO;
+        self.all[index].collision_directions = DirectionSet::EMPTY;
 
         if let Some(i) = self.by_y[y].iter().position(|&ox| ox == x) {
             self.by_y[y].remove(i);
@@ -242,15 +222,15 @@ impl MatrixObstacles {
 
     fn reset_collisions(&mut self) {
         for &i in self.visited_indices.iter() {
-            self.all[i].collision_directions = 0;
+            self.all[i].collision_directions = DirectionSet::EMPTY;
         }
 
         self.visited_indices.clear();
     }
 }
 
-fn part_2(input: &str) -> String {
-    let mut m = TraversableMatrix::<char>::from_str(input);
+fn part_2(matrix: &TraversableMatrix<char>) -> String {
+    let mut m = matrix.clone();
     let starting_position = get_starting_position(&mut m);
     let distinct_positions = get_distinct_visited_positions(&mut m);
 
@@ -278,62 +258,20 @@ fn part_2(input: &str) -> String {
         .to_string()
 }
 
-fn main() {
-    let input = read_input("2024", "06");
+pub struct Puzzle;
 
-    let start_part_1 = Instant::now();
-    let part_1_result = part_1(&input);
-    let part_1_time = start_part_1.elapsed();
+impl Solution for Puzzle {
+    type Input = TraversableMatrix<char>;
 
-    println!();
-    println!("Part 1: {} ({:?})", part_1_result, part_1_time);
-
-    let start_part_2 = Instant::now();
-    let part_2_result = part_2(&input);
-    let part_2_time = start_part_2.elapsed();
-
-    println!("Part 2: {} ({:?})", part_2_result, part_2_time);
-}
+    fn parse(input: &str) -> TraversableMatrix<char> {
+        TraversableMatrix::from_str(input)
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_1() {
-        let input = r"
-....#.....
-.........#
-..........
-..#.......
-.......#..
-..........
-.#..^.....
-........#.
-#.........
-......#...
-            "
-        .trim();
-
-        assert_eq!(part_1(input), "41");
+    fn part_1(input: &TraversableMatrix<char>, _: &Params) -> String {
+        part_1(input)
     }
 
-    #[test]
-    fn test_part_2() {
-        let input = r"
-....#.....
-.........#
-..........
-..#.......
-.......#..
-..........
-.#..^.....
-........#.
-#.........
-......#...
-            "
-        .trim();
-
-        assert_eq!(part_2(input), "6");
+    fn part_2(input: &TraversableMatrix<char>, _: &Params) -> String {
+        part_2(input)
     }
 }
@@ -0,0 +1,238 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::Client;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    // wrong, without a hint in which direction
+    Wrong,
+    // submitted too soon after the previous guess, holds the time left as the site words it
+    Wait(String),
+    // the part was already solved, or part 1 isn't solved yet
+    WrongLevel,
+    Unknown,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Guess {
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    // unix seconds
+    pub submitted_at: u64,
+}
+
+impl Outcome {
+    fn is_rejection(&self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+}
+
+pub fn history_path(data_dir: &Path, year: &str, day: &str) -> PathBuf {
+    data_dir.join(format!("guesses/year{}_day{}.json", year, day))
+}
+
+pub fn load_history(path: &Path) -> Result<Vec<Guess>, String> {
+    let Ok(json) = fs::read_to_string(path) else {
+        return Ok(vec![]);
+    };
+
+    serde_json::from_str(&json)
+        .map_err(|e| format!("Invalid guess history {}: {}", path.display(), e))
+}
+
+fn save_history(path: &Path, history: &[Guess]) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|e| format!("Could not create {}: {}", dir.display(), e))?;
+    }
+
+    let json = serde_json::to_string_pretty(history).unwrap();
+
+    fs::write(path, json + "\n").map_err(|e| format!("Could not write {}: {}", path.display(), e))
+}
+
+// refuses answers that are already known to be wrong, so they never reach the server
+pub fn check_guess(history: &[Guess], part: u8, answer: &str) -> Result<(), String> {
+    if answer.is_empty() {
+        return Err("Refusing to submit an empty answer".to_string());
+    }
+
+    let numeric_answer = answer.parse::<i128>().ok();
+
+    for guess in history.iter().filter(|guess| guess.part == part) {
+        if guess.outcome == Outcome::Correct {
+            return Err(format!(
+                "Part {} was already solved with {}",
+                part, guess.answer
+            ));
+        }
+
+        if guess.outcome.is_rejection() && guess.answer == answer {
+            return Err(format!(
+                "{} was already rejected ({:?})",
+                answer, guess.outcome
+            ));
+        }
+
+        let (Some(n), Ok(guessed)) = (numeric_answer, guess.answer.parse::<i128>()) else {
+            continue;
+        };
+
+        if guess.outcome == Outcome::TooLow && n <= guessed {
+            return Err(format!(
+                "{} can't be right, {} was already too low",
+                n, guessed
+            ));
+        }
+
+        if guess.outcome == Outcome::TooHigh && n >= guessed {
+            return Err(format!(
+                "{} can't be right, {} was already too high",
+                n, guessed
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn parse_response(html: &str) -> Outcome {
+    if html.contains("That's the right answer") {
+        return Outcome::Correct;
+    }
+
+    // checked before the wrong answer messages, which also ask you to wait
+    if html.contains("You gave an answer too recently") {
+        let left = html
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .map_or(String::new(), |(left, _)| left.to_string());
+
+        return Outcome::Wait(left);
+    }
+
+    if html.contains("Did you already complete it") {
+        return Outcome::WrongLevel;
+    }
+
+    if html.contains("That's not the right answer") {
+        return if html.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if html.contains("your answer is too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Wrong
+        };
+    }
+
+    Outcome::Unknown
+}
+
+// checks the guess against the history, posts it and records whatever the server said
+pub fn submit(
+    client: &Client,
+    history_path: &Path,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Outcome, String> {
+    let mut history = load_history(history_path)?;
+
+    check_guess(&history, part, answer)?;
+
+    let html = client.post_form(
+        &format!("/{}/day/{}/answer", year, day),
+        &[("level", &part.to_string()), ("answer", answer)],
+    )?;
+    let outcome = parse_response(&html);
+
+    history.push(Guess {
+        part,
+        answer: answer.to_string(),
+        outcome: outcome.clone(),
+        submitted_at: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs(),
+    });
+    save_history(history_path, &history)?;
+
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guess(part: u8, answer: &str, outcome: Outcome) -> Guess {
+        Guess {
+            part,
+            answer: answer.to_string(),
+            outcome,
+            submitted_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_check_guess() {
+        let history = vec![
+            guess(1, "100", Outcome::TooLow),
+            guess(1, "500", Outcome::TooHigh),
+            guess(1, "300", Outcome::Wrong),
+            guess(1, "200", Outcome::Wait("30s".to_string())),
+            guess(2, "7", Outcome::Correct),
+        ];
+
+        assert!(check_guess(&history, 1, "250").is_ok());
+        assert!(check_guess(&history, 1, "200").is_ok());
+        assert!(check_guess(&history, 1, "300").is_err());
+        assert!(check_guess(&history, 1, "100").is_err());
+        assert!(check_guess(&history, 1, "50").is_err());
+        assert!(check_guess(&history, 1, "500").is_err());
+        assert!(check_guess(&history, 1, "600").is_err());
+        assert!(check_guess(&history, 1, "").is_err());
+        assert!(check_guess(&history, 2, "8").is_err());
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            parse_response("<p>That's the right answer!  You are one gold star closer"),
+            Outcome::Correct
+        );
+        assert_eq!(
+            parse_response("<p>That's not the right answer; your answer is too high.  Please wait one minute before trying again."),
+            Outcome::TooHigh
+        );
+        assert_eq!(
+            parse_response("<p>That's not the right answer; your answer is too low."),
+            Outcome::TooLow
+        );
+        assert_eq!(
+            parse_response("<p>That's not the right answer.  If you're stuck"),
+            Outcome::Wrong
+        );
+        assert_eq!(
+            parse_response("<p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 23s left to wait."),
+            Outcome::Wait("4m 23s".to_string())
+        );
+        assert_eq!(
+            parse_response(
+                "<p>You don't seem to be solving the right level.  Did you already complete it?"
+            ),
+            Outcome::WrongLevel
+        );
+    }
+}
@@ -1,5 +1,207 @@
-#[derive(Clone, Copy, Debug)]
+use std::{
+    fmt::{self, Display},
+    ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
+};
+
+// a position in a grid or a size, ordered by x then y like (x, y) tuples
+//
+// the operators panic on overflow in debug builds like usize does, `checked_*` and `wrapping_*`
+// are there for walks that can leave the grid
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct UVec2 {
     pub x: usize,
     pub y: usize,
 }
+
+impl UVec2 {
+    pub const ZERO: UVec2 = UVec2 { x: 0, y: 0 };
+
+    pub const fn new(x: usize, y: usize) -> UVec2 {
+        UVec2 { x, y }
+    }
+
+    pub fn checked_add(self, other: UVec2) -> Option<UVec2> {
+        Some(UVec2 {
+            x: self.x.checked_add(other.x)?,
+            y: self.y.checked_add(other.y)?,
+        })
+    }
+
+    pub fn checked_sub(self, other: UVec2) -> Option<UVec2> {
+        Some(UVec2 {
+            x: self.x.checked_sub(other.x)?,
+            y: self.y.checked_sub(other.y)?,
+        })
+    }
+
+    pub fn checked_mul(self, scalar: usize) -> Option<UVec2> {
+        Some(UVec2 {
+            x: self.x.checked_mul(scalar)?,
+            y: self.y.checked_mul(scalar)?,
+        })
+    }
+
+    pub fn wrapping_add(self, other: UVec2) -> UVec2 {
+        UVec2 {
+            x: self.x.wrapping_add(other.x),
+            y: self.y.wrapping_add(other.y),
+        }
+    }
+
+    pub fn wrapping_sub(self, other: UVec2) -> UVec2 {
+        UVec2 {
+            x: self.x.wrapping_sub(other.x),
+            y: self.y.wrapping_sub(other.y),
+        }
+    }
+
+    pub fn wrapping_mul(self, scalar: usize) -> UVec2 {
+        UVec2 {
+            x: self.x.wrapping_mul(scalar),
+            y: self.y.wrapping_mul(scalar),
+        }
+    }
+
+    // steps between the two when moving up, down, left and right
+    pub fn manhattan(self, other: UVec2) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    // steps between the two when diagonal moves are allowed too
+    pub fn chebyshev(self, other: UVec2) -> usize {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+}
+
+impl Add for UVec2 {
+    type Output = UVec2;
+
+    fn add(self, other: UVec2) -> UVec2 {
+        UVec2 {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+impl Sub for UVec2 {
+    type Output = UVec2;
+
+    fn sub(self, other: UVec2) -> UVec2 {
+        UVec2 {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+impl Mul<usize> for UVec2 {
+    type Output = UVec2;
+
+    fn mul(self, scalar: usize) -> UVec2 {
+        UVec2 {
+            x: self.x * scalar,
+            y: self.y * scalar,
+        }
+    }
+}
+
+impl AddAssign for UVec2 {
+    fn add_assign(&mut self, other: UVec2) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for UVec2 {
+    fn sub_assign(&mut self, other: UVec2) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign<usize> for UVec2 {
+    fn mul_assign(&mut self, scalar: usize) {
+        *self = *self * scalar;
+    }
+}
+
+impl From<(usize, usize)> for UVec2 {
+    fn from((x, y): (usize, usize)) -> UVec2 {
+        UVec2 { x, y }
+    }
+}
+
+impl From<UVec2> for (usize, usize) {
+    fn from(v: UVec2) -> (usize, usize) {
+        (v.x, v.y)
+    }
+}
+
+// `x,y`, the way positions are written in puzzles and answers
+impl Display for UVec2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let mut v = UVec2::new(3, 4);
+
+        assert_eq!(v + UVec2::new(1, 2), UVec2::new(4, 6));
+        assert_eq!(v - UVec2::new(1, 2), UVec2::new(2, 2));
+        assert_eq!(v * 3, UVec2::new(9, 12));
+
+        v += UVec2::new(1, 1);
+        v -= UVec2::new(2, 0);
+        v *= 2;
+
+        assert_eq!(v, UVec2::new(4, 10));
+        assert_eq!(v.checked_sub(UVec2::new(5, 0)), None);
+        assert_eq!(v.checked_sub(UVec2::new(4, 0)), Some(UVec2::new(0, 10)));
+        assert_eq!(v.checked_add(UVec2::new(usize::MAX, 0)), None);
+        assert_eq!(v.checked_mul(usize::MAX), None);
+        assert_eq!(
+            UVec2::ZERO.wrapping_sub(UVec2::new(1, 0)),
+            UVec2::new(usize::MAX, 0)
+        );
+        assert_eq!(
+            UVec2::new(usize::MAX, 1).wrapping_add(UVec2::new(1, 1)),
+            UVec2::new(0, 2)
+        );
+        assert_eq!(UVec2::new(1, 2).wrapping_mul(2), UVec2::new(2, 4));
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = UVec2::new(1, 6);
+        let b = UVec2::new(5, 11);
+
+        assert_eq!(a.manhattan(b), 9);
+        assert_eq!(b.manhattan(a), 9);
+        assert_eq!(a.chebyshev(b), 5);
+    }
+
+    #[test]
+    fn test_conversions() {
+        let v: UVec2 = (2, 7).into();
+
+        assert_eq!(v, UVec2::new(2, 7));
+        assert_eq!(<(usize, usize)>::from(v), (2, 7));
+        assert_eq!(v.to_string(), "2,7");
+        assert!(UVec2::new(1, 9) < UVec2::new(2, 0));
+        assert!(UVec2::new(2, 0) < UVec2::new(2, 1));
+        assert_eq!(
+            [v, UVec2::new(2, 7), UVec2::ZERO]
+                .into_iter()
+                .collect::<HashSet<UVec2>>()
+                .len(),
+            2
+        );
+    }
+}
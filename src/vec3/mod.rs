@@ -0,0 +1,261 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+use itertools::Itertools;
+use num_rational::Ratio;
+use num_traits::Zero;
+
+// exact fractions for intersections and the like, big enough for products of puzzle coordinates
+// (~10^15) with velocities, where f64 starts rounding
+pub type Rational = Ratio<i128>;
+
+// a point or a velocity in 3D, Vec3<usize> / Vec3<i64> for integer puzzles, Vec3<Rational> for
+// exact maths on them
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vec3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Vec3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Vec3<T> {
+        Vec3 { x, y, z }
+    }
+
+    pub fn map<U>(self, f: impl Fn(T) -> U) -> Vec3<U> {
+        Vec3::new(f(self.x), f(self.y), f(self.z))
+    }
+}
+
+impl<T: Zero> Vec3<T> {
+    pub fn zero() -> Vec3<T> {
+        Vec3::new(T::zero(), T::zero(), T::zero())
+    }
+}
+
+impl<T: Copy + Add<Output = T> + Mul<Output = T>> Vec3<T> {
+    pub fn dot(self, other: Vec3<T>) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+}
+
+impl<T: Copy + Sub<Output = T> + Mul<Output = T>> Vec3<T> {
+    // with z = 0 for both, the z of the result is the 2D cross product
+    pub fn cross(self, other: Vec3<T>) -> Vec3<T> {
+        Vec3::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+}
+
+impl<T: Copy + PartialOrd> Vec3<T> {
+    // per coordinate
+    pub fn min(self, other: Vec3<T>) -> Vec3<T> {
+        let min = |a: T, b: T| if b < a { b } else { a };
+
+        Vec3::new(
+            min(self.x, other.x),
+            min(self.y, other.y),
+            min(self.z, other.z),
+        )
+    }
+
+    pub fn max(self, other: Vec3<T>) -> Vec3<T> {
+        let max = |a: T, b: T| if b > a { b } else { a };
+
+        Vec3::new(
+            max(self.x, other.x),
+            max(self.y, other.y),
+            max(self.z, other.z),
+        )
+    }
+}
+
+impl<T: FromStr> Vec3<T> {
+    // `x, y, z @ dx, dy, dz`, a position and a velocity
+    pub fn parse_ray(s: &str) -> Result<(Vec3<T>, Vec3<T>), String> {
+        let (position, velocity) = s
+            .split_once('@')
+            .ok_or_else(|| format!("Expected `position @ velocity`, got {:?}", s))?;
+
+        Ok((position.parse()?, velocity.parse()?))
+    }
+}
+
+// `x,y,z`, spaces around the numbers are fine
+impl<T: FromStr> FromStr for Vec3<T> {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Vec3<T>, String> {
+        s.split(',')
+            .map(|coord| coord.trim().parse::<T>().ok())
+            .collect::<Option<Vec<T>>>()
+            .and_then(|coords| coords.into_iter().collect_tuple())
+            .map(|(x, y, z)| Vec3::new(x, y, z))
+            .ok_or_else(|| format!("Expected `x,y,z`, got {:?}", s))
+    }
+}
+
+impl<T: Add<Output = T>> Add for Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn add(self, other: Vec3<T>) -> Vec3<T> {
+        Vec3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn sub(self, other: Vec3<T>) -> Vec3<T> {
+        Vec3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn mul(self, scalar: T) -> Vec3<T> {
+        Vec3::new(self.x * scalar, self.y * scalar, self.z * scalar)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn neg(self) -> Vec3<T> {
+        Vec3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<T: Copy + Add<Output = T>> AddAssign for Vec3<T> {
+    fn add_assign(&mut self, other: Vec3<T>) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Copy + Sub<Output = T>> SubAssign for Vec3<T> {
+    fn sub_assign(&mut self, other: Vec3<T>) {
+        *self = *self - other;
+    }
+}
+
+impl<T> From<(T, T, T)> for Vec3<T> {
+    fn from((x, y, z): (T, T, T)) -> Vec3<T> {
+        Vec3::new(x, y, z)
+    }
+}
+
+impl<T: Display> Display for Vec3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+// the smallest box with both corners in it, edges included
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BoundingBox<T> {
+    pub min: Vec3<T>,
+    pub max: Vec3<T>,
+}
+
+impl<T: Copy + PartialOrd> BoundingBox<T> {
+    pub fn new(a: Vec3<T>, b: Vec3<T>) -> BoundingBox<T> {
+        BoundingBox {
+            min: a.min(b),
+            max: a.max(b),
+        }
+    }
+
+    // None if there are no points
+    pub fn around(points: impl IntoIterator<Item = Vec3<T>>) -> Option<BoundingBox<T>> {
+        points
+            .into_iter()
+            .map(|point| BoundingBox::new(point, point))
+            .reduce(|a, b| BoundingBox::new(a.min.min(b.min), a.max.max(b.max)))
+    }
+
+    pub fn contains(&self, point: Vec3<T>) -> bool {
+        self.min.x <= point.x
+            && point.x <= self.max.x
+            && self.min.y <= point.y
+            && point.y <= self.max.y
+            && self.min.z <= point.z
+            && point.z <= self.max.z
+    }
+
+    // true if the boxes share at least a point, touching edges included
+    pub fn intersects(&self, other: &BoundingBox<T>) -> bool {
+        self.min.x <= other.max.x
+            && other.min.x <= self.max.x
+            && self.min.y <= other.max.y
+            && other.min.y <= self.max.y
+            && self.min.z <= other.max.z
+            && other.min.z <= self.max.z
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Vec3::new(1, -2, 3);
+        let b = Vec3::new(4, 5, -6);
+
+        assert_eq!(a + b, Vec3::new(5, 3, -3));
+        assert_eq!(a - b, Vec3::new(-3, -7, 9));
+        assert_eq!(a * 2, Vec3::new(2, -4, 6));
+        assert_eq!(-a, Vec3::new(-1, 2, -3));
+        assert_eq!(a.dot(b), -24);
+        assert_eq!(a.cross(b), Vec3::new(-3, 18, 13));
+        assert_eq!(a.cross(b).dot(a), 0);
+        assert_eq!(Vec3::<i64>::zero(), Vec3::new(0, 0, 0));
+
+        // 1/3 can't be an f64
+        let third = Vec3::new(1, 2, 3).map(|c| Rational::new(c, 3));
+
+        assert_eq!(
+            third * Rational::from_integer(3),
+            Vec3::new(1, 2, 3).map(Rational::from)
+        );
+        assert_eq!(third.to_string(), "1/3,2/3,1");
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("1,0,1".parse::<Vec3<usize>>(), Ok(Vec3::new(1, 0, 1)));
+        assert_eq!(
+            Vec3::<i64>::parse_ray("19, 13, 30 @ -2,  1, -2"),
+            Ok((Vec3::new(19, 13, 30), Vec3::new(-2, 1, -2)))
+        );
+        assert!("1,0".parse::<Vec3<usize>>().is_err());
+        assert!("1,0,-1".parse::<Vec3<usize>>().is_err());
+        assert!(Vec3::<i64>::parse_ray("19, 13, 30").is_err());
+        assert!("1,2,3,4".parse::<Vec3<i32>>().is_err());
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let a = BoundingBox::new(Vec3::new(1, 0, 1), Vec3::new(1, 2, 1));
+        let b = BoundingBox::new(Vec3::new(2, 2, 2), Vec3::new(0, 2, 2));
+
+        assert_eq!(b.min, Vec3::new(0, 2, 2));
+        assert!(!a.intersects(&b));
+        assert!(a.intersects(&BoundingBox::new(Vec3::new(0, 2, 1), Vec3::new(2, 2, 1))));
+        assert!(a.contains(Vec3::new(1, 1, 1)));
+        assert!(!a.contains(Vec3::new(1, 3, 1)));
+        assert_eq!(
+            BoundingBox::around([Vec3::new(3, -1, 0), Vec3::new(-2, 4, 1)]),
+            Some(BoundingBox::new(Vec3::new(-2, -1, 0), Vec3::new(3, 4, 1)))
+        );
+        assert_eq!(BoundingBox::<i64>::around([]), None);
+    }
+}
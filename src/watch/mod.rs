@@ -0,0 +1,247 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    thread::sleep,
+    time::{Duration, SystemTime},
+};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+// modification time and size of every file under the watched directories
+type Snapshot = HashMap<PathBuf, (SystemTime, u64)>;
+
+// polls directories for changes, there's no dependency on platform file notification APIs
+pub struct Watcher {
+    roots: Vec<PathBuf>,
+    snapshot: Snapshot,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct TestSummary {
+    pub passed: usize,
+    pub failed: usize,
+    pub ignored: usize,
+    pub failures: Vec<TestFailure>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct TestFailure {
+    pub name: String,
+    // what the test printed, usually where and why it panicked
+    pub output: String,
+}
+
+fn collect(dir: &Path, snapshot: &mut Snapshot) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+
+        if metadata.is_dir() {
+            collect(&path, snapshot);
+        } else if let Ok(modified) = metadata.modified() {
+            snapshot.insert(path, (modified, metadata.len()));
+        }
+    }
+}
+
+impl Watcher {
+    pub fn new(roots: Vec<PathBuf>) -> Watcher {
+        let snapshot = Watcher::take_snapshot(&roots);
+
+        Watcher { roots, snapshot }
+    }
+
+    fn take_snapshot(roots: &[PathBuf]) -> Snapshot {
+        let mut snapshot = Snapshot::new();
+
+        for root in roots {
+            collect(root, &mut snapshot);
+        }
+
+        snapshot
+    }
+
+    // whether anything was added, removed or modified since the last call
+    pub fn changed(&mut self) -> bool {
+        let snapshot = Watcher::take_snapshot(&self.roots);
+        let changed = snapshot != self.snapshot;
+
+        self.snapshot = snapshot;
+
+        changed
+    }
+
+    // blocks until something changes, then until nothing has changed for `debounce`, so that
+    // an editor saving several files at once only triggers one run
+    pub fn wait(&mut self, debounce: Duration) {
+        while !self.changed() {
+            sleep(POLL_INTERVAL);
+        }
+
+        loop {
+            sleep(debounce);
+
+            if !self.changed() {
+                return;
+            }
+        }
+    }
+}
+
+// the `---- <name> stdout ----` sections cargo prints for failed tests, without the blank lines
+// and the backtrace hint
+fn failure_outputs(output: &str) -> HashMap<&str, String> {
+    let mut outputs = HashMap::new();
+    let mut current: Option<(&str, Vec<&str>)> = None;
+
+    for line in output.lines() {
+        let header = line
+            .strip_prefix("---- ")
+            .and_then(|line| line.strip_suffix(" stdout ----"));
+
+        if header.is_some() || line == "failures:" {
+            if let Some((name, lines)) = current.take() {
+                outputs.insert(name, lines.join("\n"));
+            }
+        }
+
+        if let Some(name) = header {
+            current = Some((name, vec![]));
+        } else if let Some((_, lines)) = &mut current {
+            if !line.trim().is_empty() && !line.starts_with("note: run with `RUST_BACKTRACE") {
+                lines.push(line);
+            }
+        }
+    }
+
+    outputs
+}
+
+// None if there's no `test result:` line, i.e. the tests didn't build
+pub fn parse_test_output(output: &str) -> Option<TestSummary> {
+    let mut summary = TestSummary::default();
+    let mut outputs = failure_outputs(output);
+    let mut found = false;
+
+    for line in output.lines() {
+        if let Some(name) = line
+            .strip_prefix("test ")
+            .and_then(|line| line.strip_suffix(" ... FAILED"))
+        {
+            summary.failures.push(TestFailure {
+                name: name.to_string(),
+                output: outputs.remove(name).unwrap_or_default(),
+            });
+        }
+
+        let Some(counts) = line.strip_prefix("test result: ") else {
+            continue;
+        };
+
+        found = true;
+
+        for count in counts.split(['.', ';']) {
+            let mut words = count.split_whitespace();
+            let (Some(Ok(n)), Some(what)) = (words.next().map(str::parse::<usize>), words.next())
+            else {
+                continue;
+            };
+
+            match what {
+                "passed" => summary.passed += n,
+                "failed" => summary.failed += n,
+                "ignored" => summary.ignored += n,
+                _ => {}
+            }
+        }
+    }
+
+    found.then_some(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    #[test]
+    fn test_watcher() {
+        let dir = env::temp_dir().join(format!("aoc_rust_watch_{}", std::process::id()));
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        fs::write(dir.join("a.txt"), "a").unwrap();
+
+        let mut watcher = Watcher::new(vec![dir.clone()]);
+
+        assert!(!watcher.changed());
+
+        fs::write(dir.join("nested/b.txt"), "b").unwrap();
+
+        assert!(watcher.changed());
+        assert!(!watcher.changed());
+
+        fs::write(dir.join("a.txt"), "longer").unwrap();
+
+        assert!(watcher.changed());
+
+        fs::remove_file(dir.join("a.txt")).unwrap();
+
+        assert!(watcher.changed());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_parse_test_output() {
+        let output = "
+running 3 tests
+test solutions::year2023_day05::tests::test_part_1 ... ok
+test solutions::year2023_day05::tests::test_part_2 ... FAILED
+test solutions::year2023_day05::tests::test_slow ... ignored
+
+failures:
+
+---- solutions::year2023_day05::tests::test_part_2 stdout ----
+
+thread 'solutions::year2023_day05::tests::test_part_2' panicked at src/solutions/year2023_day05.rs:9:5:
+assertion `left == right` failed
+  left: \"45\"
+ right: \"46\"
+note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
+
+
+failures:
+    solutions::year2023_day05::tests::test_part_2
+
+test result: FAILED. 1 passed; 1 failed; 1 ignored; 0 measured; 90 filtered out; finished in 0.01s
+";
+
+        assert_eq!(
+            parse_test_output(output),
+            Some(TestSummary {
+                passed: 1,
+                failed: 1,
+                ignored: 1,
+                failures: vec![TestFailure {
+                    name: "solutions::year2023_day05::tests::test_part_2".to_string(),
+                    output: [
+                        "thread 'solutions::year2023_day05::tests::test_part_2' panicked at \
+                         src/solutions/year2023_day05.rs:9:5:",
+                        "assertion `left == right` failed",
+                        "  left: \"45\"",
+                        " right: \"46\"",
+                    ]
+                    .join("\n"),
+                }],
+            })
+        );
+        assert_eq!(parse_test_output("error[E0308]: mismatched types"), None);
+    }
+}
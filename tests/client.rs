@@ -0,0 +1,129 @@
+use std::{
+    env, fs,
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    thread,
+};
+
+use aoc_rust::{
+    client::{Client, Fetched},
+    submit::{self, Outcome},
+};
+
+// answers a single request with `body` and hands back the raw request it received
+fn stub_server(body: &'static str) -> (String, thread::JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+    let handle = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        let mut request = String::new();
+        let mut content_length = 0;
+
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+
+            if line == "\r\n" {
+                break;
+            }
+
+            if let Some(length) = line.strip_prefix("Content-Length: ") {
+                content_length = length.trim().parse().unwrap();
+            }
+
+            request.push_str(&line);
+        }
+
+        let mut request_body = vec![0; content_length];
+        reader.read_exact(&mut request_body).unwrap();
+        request.push_str(&String::from_utf8(request_body).unwrap());
+
+        write!(
+            stream,
+            "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        )
+        .unwrap();
+
+        request
+    });
+
+    (base_url, handle)
+}
+
+#[test]
+fn fetch_input_downloads_once() {
+    let (base_url, server) = stub_server("1 2 3\n");
+    let path = env::temp_dir().join(format!(
+        "aoc_rust_fetch_{}/year2023_day05.txt",
+        std::process::id()
+    ));
+    let _ = fs::remove_file(&path);
+
+    let client = Client::new(&base_url, Some("abc123"));
+
+    assert_eq!(client.fetch_input(2023, 5, &path), Ok(Fetched::Downloaded));
+    assert_eq!(fs::read_to_string(&path).unwrap(), "1 2 3\n");
+
+    let request = server.join().unwrap();
+
+    assert!(request.starts_with("GET /2023/day/5/input HTTP/1.1"));
+    assert!(request.contains("Cookie: session=abc123"));
+    assert!(request.contains("User-Agent: github.com/NikolaIliev/aoc_rust"));
+
+    // the stub only answers once, so this would fail if it tried to download again
+    assert_eq!(client.fetch_input(2023, 5, &path), Ok(Fetched::Cached));
+
+    fs::remove_dir_all(path.parent().unwrap()).unwrap();
+}
+
+#[test]
+fn fetch_input_requires_session() {
+    let path = env::temp_dir().join("aoc_rust_fetch_no_session.txt");
+    let client = Client::new("http://127.0.0.1:1", None);
+
+    assert!(client.fetch_input(2023, 5, &path).is_err());
+    assert!(!path.exists());
+}
+
+#[test]
+fn submit_records_guesses_and_checks_bounds() {
+    let (base_url, server) = stub_server(
+        "<article><p>That's not the right answer; your answer is too low.</p></article>",
+    );
+    let history = env::temp_dir().join(format!(
+        "aoc_rust_submit_{}/year2023_day05.json",
+        std::process::id()
+    ));
+    let _ = fs::remove_file(&history);
+
+    let client = Client::new(&base_url, Some("abc123"));
+
+    assert_eq!(
+        submit::submit(&client, &history, 2023, 5, 1, "1234"),
+        Ok(Outcome::TooLow)
+    );
+
+    let request = server.join().unwrap();
+
+    assert!(request.starts_with("POST /2023/day/5/answer HTTP/1.1"));
+    assert!(request.ends_with("level=1&answer=1234"));
+
+    let guesses = submit::load_history(&history).unwrap();
+
+    assert_eq!(guesses.len(), 1);
+    assert_eq!(guesses[0].answer, "1234");
+    assert_eq!(guesses[0].outcome, Outcome::TooLow);
+
+    // the stub is gone, so these only pass if they're refused before any request is made
+    assert!(submit::submit(&client, &history, 2023, 5, 1, "1234")
+        .is_err_and(|e| e.contains("already rejected")));
+    assert!(
+        submit::submit(&client, &history, 2023, 5, 1, "1000").is_err_and(|e| e.contains("too low"))
+    );
+
+    fs::remove_dir_all(history.parent().unwrap()).unwrap();
+}
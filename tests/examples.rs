@@ -0,0 +1,16 @@
+use aoc_rust::{examples, solutions};
+
+// unused when no enabled year has examples, e.g. with --no-default-features
+#[allow(dead_code)]
+fn check(year: u16, day: u8, name: &str, part: u8) {
+    let day = solutions::find(year, day).expect("no solution registered");
+    let dir = examples::day_dir(&examples::examples_dir(), day.year, day.day);
+    let result =
+        examples::load_example(&dir, name).and_then(|example| examples::check(day, &example, part));
+
+    if let Err(e) = result {
+        panic!("{}", e);
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
@@ -0,0 +1,32 @@
+use std::hint::black_box;
+
+use aoc_rust::memory::{self, CountingAllocator};
+
+// its own test binary, so the counting allocator doesn't see what the lib tests allocate
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn test_measure() {
+    assert!(memory::enable());
+
+    let (sum, allocs) = memory::measure(|| {
+        let big = black_box(vec![1u64; 1000]);
+        let sum: u64 = big.iter().sum();
+        drop(big);
+
+        let small = black_box(vec![1u64; 10]);
+
+        sum + small.iter().sum::<u64>()
+    });
+
+    assert_eq!(sum, 1010);
+    assert_eq!(
+        allocs,
+        Some(memory::Allocs {
+            count: 2,
+            bytes: 8080,
+            peak_bytes: 8000,
+        })
+    );
+}